@@ -0,0 +1,137 @@
+// Finds every `src/days/<year>/<person>/dayN.rs` and generates the module tree for each year and person
+// along with `get_solutions`, so adding a day only means adding its file.
+// Any other `.rs` file in a person's folder (like `shared.rs`) becomes a module next to their days.
+
+use std::{collections::BTreeMap, env, fs, path::Path};
+
+const DAYS_DIR: &str = "src/days";
+// The same as `days::LAST_DAY`, which the build script can't use
+const LAST_DAY: usize = 25;
+
+fn day_number(filename: &str) -> Option<usize> {
+    let number = filename.strip_prefix("day")?.strip_suffix(".rs")?;
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+fn variant(person: &str) -> String {
+    let mut chars = person.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn sorted_entries(dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", dir.display()))
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap().to_str().unwrap().to_owned()
+}
+
+#[derive(Default)]
+struct PersonDays {
+    // day number => file name
+    days: BTreeMap<usize, String>,
+    // Every other module, like `shared`
+    modules: Vec<String>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={DAYS_DIR}");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let days_dir = Path::new(&manifest_dir).join(DAYS_DIR);
+
+    // year => person => their days
+    let mut years: BTreeMap<usize, BTreeMap<String, PersonDays>> = BTreeMap::new();
+    let mut errors = vec![];
+
+    for year_dir in sorted_entries(&days_dir).into_iter().filter(|path| path.is_dir()) {
+        let Ok(year) = file_name(&year_dir).parse::<usize>() else {
+            errors.push(format!("{DAYS_DIR}/{} should be named after a year", file_name(&year_dir)));
+            continue;
+        };
+        // Every year gets rebuilt when a person is added to it
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        let people = years.entry(year).or_default();
+
+        for person_dir in sorted_entries(&year_dir).into_iter().filter(|path| path.is_dir()) {
+            println!("cargo:rerun-if-changed={}", person_dir.display());
+            let person = file_name(&person_dir);
+            let person_days = people.entry(person.clone()).or_default();
+
+            for filename in sorted_entries(&person_dir).iter().map(|path| file_name(path)) {
+                let Some(module) = filename.strip_suffix(".rs") else {
+                    continue;
+                };
+                let Some(day) = day_number(&filename) else {
+                    if module != "mod" {
+                        person_days.modules.push(module.to_owned());
+                    }
+                    continue;
+                };
+                let path = format!("{DAYS_DIR}/{year}/{person}/{filename}");
+                if day == 0 || day > LAST_DAY {
+                    errors.push(format!("{path} is not a day between 1 and {LAST_DAY}"));
+                } else if let Some(existing) = person_days.days.get(&day) {
+                    errors.push(format!("{path} and {DAYS_DIR}/{year}/{person}/{existing} both register {person} day {day} of {year}"));
+                } else {
+                    person_days.days.insert(day, filename);
+                }
+            }
+        }
+    }
+
+    let mut generated = String::new();
+    for error in &errors {
+        generated.push_str(&format!("compile_error!({error:?});\n"));
+    }
+
+    // The modules of each year and person, included by `days.rs`
+    for (year, people) in &years {
+        generated.push_str(&format!("pub mod y{year} {{\n"));
+        for (person, person_days) in people {
+            generated.push_str(&format!("    pub mod {person} {{\n"));
+            let dir = days_dir.join(year.to_string()).join(person);
+            let modules = person_days.modules.iter().map(|x| (x.clone(), format!("{x}.rs")))
+                .chain(person_days.days.iter().map(|(day, filename)| (format!("day{day}"), filename.clone())));
+            for (module, filename) in modules {
+                generated.push_str(&format!("        #[path = {:?}]\n        pub mod {module};\n", dir.join(filename).to_str().unwrap()));
+            }
+            generated.push_str("    }\n");
+        }
+        generated.push_str("}\n");
+    }
+
+    // Every year with solutions, oldest first
+    let year_list = years.keys().map(|x| x.to_string()).collect::<Vec<String>>().join(", ");
+    generated.push_str(&format!("pub const YEARS: &[usize] = &[{year_list}];\n"));
+
+    generated.push_str("pub fn get_solutions(year: usize, by: Person) -> HashMap<usize, Box<dyn Solution>> {\n");
+    generated.push_str("    let mut result: HashMap<usize, Box<dyn Solution>> = HashMap::new();\n");
+    generated.push_str("    match (year, by) {\n");
+    for (year, people) in &years {
+        for (person, person_days) in people {
+            generated.push_str(&format!("        ({year}, Person::{}) => {{\n", variant(person)));
+            for day in person_days.days.keys() {
+                generated.push_str(&format!("            result.insert({day}, Box::new(y{year}::{person}::day{day}::Day{day}::new()));\n"));
+            }
+            generated.push_str("        }\n");
+        }
+    }
+    generated.push_str("        _ => {}\n");
+    generated.push_str("    }\n");
+    generated.push_str("    result\n");
+    generated.push_str("}\n");
+    fs::write(Path::new(&out_dir).join("solutions.rs"), generated).unwrap();
+}
@@ -0,0 +1,149 @@
+// Counts what each phase allocates when built with `--features alloc-stats`.
+// The counts are kept per thread, so days solved with `--jobs` don't see each other's allocations.
+
+use std::cell::Cell;
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // The most memory the phase held at once, on top of what was live when it started
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhaseAllocs {
+    pub parse: AllocStats,
+    pub prepare: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl PhaseAllocs {
+    pub fn total(&self) -> AllocStats {
+        let phases = [self.parse, self.prepare, self.part1, self.part2];
+        AllocStats {
+            allocations: phases.iter().map(|x| x.allocations).sum(),
+            bytes: phases.iter().map(|x| x.bytes).sum(),
+            // The highest of any one phase, since each one measures from what was live when it started
+            peak: phases.iter().map(|x| x.peak).max().unwrap(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    // Memory can be freed by another thread than the one that allocated it, so this can go negative
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Const initialized so using it never allocates
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 }) };
+}
+
+// Without the feature nothing is counted, so every phase reports zero
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::{Counters, COUNTERS};
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        // Fails while the thread is being torn down, when nobody is measuring anymore
+        let _ = COUNTERS.try_with(|counters| {
+            let mut x = counters.get();
+            f(&mut x);
+            counters.set(x);
+        });
+    }
+
+    fn allocated(size: usize) {
+        update(|x| {
+            x.allocations += 1;
+            x.bytes += size as u64;
+            x.live += size as i64;
+            x.peak = x.peak.max(x.live);
+        });
+    }
+
+    fn freed(size: usize) {
+        update(|x| x.live -= size as i64);
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        // Growing a `Vec` counts as allocating the new size and freeing the old one
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+}
+
+// Measures the allocations of the current thread from `start` until `finish`
+pub struct Tracker {
+    start: Counters,
+}
+
+impl Tracker {
+    pub fn start() -> Tracker {
+        let mut start = COUNTERS.get();
+        start.peak = start.live;
+        COUNTERS.set(start);
+        Tracker { start }
+    }
+
+    pub fn finish(self) -> AllocStats {
+        let now = COUNTERS.get();
+        AllocStats {
+            allocations: now.allocations - self.start.allocations,
+            bytes: now.bytes - self.start.bytes,
+            peak: (now.peak - self.start.live).max(0) as u64,
+        }
+    }
+}
+
+pub fn get_formatted_bytes(bytes: u64) -> String {
+    if bytes >= 10 << 20 {
+        format!("{}MiB", bytes >> 20)
+    } else if bytes >= 10 << 10 {
+        format!("{}KiB", bytes >> 10)
+    } else {
+        format!("{}B", bytes)
+    }
+}
@@ -0,0 +1,294 @@
+use std::{collections::{BTreeMap, BTreeSet}, fs, io::Write, path::Path, time::Duration};
+
+use crate::bench::Stats;
+use crate::days::{get_formatted_time, Answer};
+use crate::output::DayResult;
+
+pub const BASELINE_DIRECTORY: &str = ".bench";
+
+const HEADER: &str = "# year\tperson\tday\tphase\tsamples\tmean_ns\tstddev_ns\tmedian_ns";
+
+const PHASES: [&str; 5] = ["Parsing", "Prepare", "Part 1", "Part 2", "Total"];
+
+// Two-sided 95% critical values of Student's t distribution for 1 to 30 degrees of freedom
+const T_CRITICAL: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+    2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+    2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+];
+
+// The summary of one phase of one day, which is all we need to test for a significant change
+#[derive(Clone, Copy, Debug)]
+pub struct Summary {
+    pub samples: usize,
+    pub mean: f64,
+    pub stddev: f64,
+    pub median: f64,
+}
+
+impl Summary {
+    fn from(samples: usize, stats: &Stats) -> Summary {
+        Summary {
+            samples,
+            mean: stats.mean.as_nanos() as f64,
+            stddev: stats.stddev.as_nanos() as f64,
+            median: stats.median.as_nanos() as f64,
+        }
+    }
+}
+
+// (year, person, day, phase)
+type Key = (usize, String, usize, String);
+
+pub struct Baseline {
+    summaries: BTreeMap<Key, Summary>,
+}
+
+fn baseline_path(name: &str) -> String {
+    format!("{BASELINE_DIRECTORY}/{name}.tsv")
+}
+
+// Every benchmarked phase of a day. Examples and other inputs aren't kept, since they'd be mixed up with the real input.
+pub fn summaries(result: &DayResult) -> Vec<(&'static str, Summary)> {
+    let Some(benchmark) = result.benchmark.as_ref().filter(|_| result.puzzle_input) else {
+        return vec![];
+    };
+    let stats = [Some(&benchmark.parse), Some(&benchmark.prepare), benchmark.part1.as_ref(), benchmark.part2.as_ref(), benchmark.total.as_ref()];
+    // Skipped parts and the total of a partial run have nothing to compare
+    PHASES.iter().zip(stats)
+        .filter_map(|(phase, stats)| Some((*phase, Summary::from(benchmark.samples, stats?))))
+        .collect()
+}
+
+impl Baseline {
+    pub fn from(results: &[DayResult]) -> Baseline {
+        let mut summaries = BTreeMap::new();
+        for result in results {
+            for (phase, summary) in self::summaries(result) {
+                summaries.insert((result.year, result.person.name().to_owned(), result.day, phase.to_owned()), summary);
+            }
+        }
+        Baseline { summaries }
+    }
+
+    pub fn load(name: &str) -> Result<Baseline, String> {
+        let filepath = baseline_path(name);
+        let contents = fs::read_to_string(&filepath).map_err(|e| format!("Failed to read baseline {filepath}: {e}"))?;
+        let mut summaries = BTreeMap::new();
+        for (idx, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || format!("{filepath}:{}: malformed baseline entry '{line}'", idx + 1);
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 8 {
+                return Err(bad_line());
+            }
+            let number = |idx: usize| fields[idx].parse::<f64>().map_err(|_| bad_line());
+            let summary = Summary {
+                samples: fields[4].parse().map_err(|_| bad_line())?,
+                mean: number(5)?,
+                stddev: number(6)?,
+                median: number(7)?,
+            };
+            let year = fields[0].parse().map_err(|_| bad_line())?;
+            let day = fields[2].parse().map_err(|_| bad_line())?;
+            summaries.insert((year, fields[1].to_owned(), day, fields[3].to_owned()), summary);
+        }
+        Ok(Baseline { summaries })
+    }
+
+    // Replace the days this run benchmarked in the baseline saved as `name`, keeping the rest of its days.
+    // Returns where it was saved and how many days were kept.
+    pub fn save_over(self, name: &str) -> Result<(String, usize), String> {
+        let mut merged = if Path::new(&baseline_path(name)).exists() {
+            Baseline::load(name)?
+        } else {
+            Baseline { summaries: BTreeMap::new() }
+        };
+        let day = |key: &Key| (key.0, key.1.clone(), key.2);
+        let days = self.summaries.keys().map(day).collect::<BTreeSet<_>>();
+        // Phases that weren't run this time, like the total of a `--part` run, go too
+        merged.summaries.retain(|key, _| !days.contains(&day(key)));
+        let kept = merged.summaries.keys().map(day).collect::<BTreeSet<_>>().len();
+        merged.summaries.extend(self.summaries);
+        let filepath = merged.save(name).map_err(|e| format!("Failed to save baseline {name}: {e}"))?;
+        Ok((filepath, kept))
+    }
+
+    fn save(&self, name: &str) -> std::io::Result<String> {
+        let filepath = baseline_path(name);
+        if let Some(parent) = Path::new(&filepath).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut contents = String::from(HEADER);
+        contents.push('\n');
+        for ((year, person, day, phase), summary) in &self.summaries {
+            contents.push_str(&format!(
+                "{year}\t{person}\t{day}\t{phase}\t{}\t{}\t{}\t{}\n",
+                summary.samples, summary.mean, summary.stddev, summary.median
+            ));
+        }
+        fs::write(&filepath, contents)?;
+        Ok(filepath)
+    }
+}
+
+// Welch's t-test: is the new mean significantly different from the old one?
+fn significant(old: &Summary, new: &Summary) -> bool {
+    if old.samples < 2 || new.samples < 2 {
+        return false;
+    }
+    let old_variance = old.stddev.powi(2) / old.samples as f64;
+    let new_variance = new.stddev.powi(2) / new.samples as f64;
+    let standard_error = (old_variance + new_variance).sqrt();
+    if standard_error == 0. {
+        return old.mean != new.mean;
+    }
+    let t = (new.mean - old.mean).abs() / standard_error;
+
+    // Welch–Satterthwaite approximation of the degrees of freedom
+    let degrees_of_freedom = (old_variance + new_variance).powi(2)
+        / (old_variance.powi(2) / (old.samples - 1) as f64 + new_variance.powi(2) / (new.samples - 1) as f64);
+    let critical = T_CRITICAL.get((degrees_of_freedom.floor() as usize).max(1) - 1).copied().unwrap_or(1.960);
+    t > critical
+}
+
+fn parts_name(ran: &dyn Fn(&str) -> bool) -> &'static str {
+    match (ran("Part 1"), ran("Part 2")) {
+        (true, true) => "both parts",
+        (true, false) => "only part 1",
+        (false, true) => "only part 2",
+        (false, false) => "neither part",
+    }
+}
+
+// Prints the per-day, per-phase change from the baseline.
+// Returns the number of phases that got significantly slower by more than `threshold` percent.
+pub fn compare(baseline: &Baseline, results: &[DayResult], threshold: f64, out: &mut dyn Write) -> std::io::Result<usize> {
+    let mut regressions = vec![];
+    for result in results {
+        if !result.puzzle_input {
+            writeln!(out, "Day {:2}: not compared, {} isn't the real input", result.day, result.input)?;
+            continue;
+        }
+        let key = |phase: &str| (result.year, result.person.name().to_owned(), result.day, phase.to_owned());
+        let baseline_ran = |phase: &str| baseline.summaries.contains_key(&key(phase));
+        let in_baseline = PHASES.iter().any(|x| baseline_ran(x));
+
+        // A day that got too slow to finish or started panicking is the worst regression of all
+        if result.benchmark.is_none() {
+            if in_baseline {
+                let why = if result.crash.is_some() {
+                    "crashed"
+                } else if [&result.part1, &result.part2].contains(&&Answer::TimedOut) {
+                    "timed out"
+                } else {
+                    "wasn't benchmarked"
+                };
+                writeln!(out, "Day {:2}: {why}, but it finished in the baseline", result.day)?;
+                regressions.push(format!("day {} {why}", result.day));
+            }
+            continue;
+        }
+        let summaries = summaries(result);
+
+        // Running only one part changes how long everything else takes too, so only the same parts can be compared
+        let ran = |phase: &str| summaries.iter().any(|x| x.0 == phase);
+        if in_baseline && ["Part 1", "Part 2"].iter().any(|x| ran(x) != baseline_ran(x)) {
+            writeln!(out, "Day {:2}: ran {} but the baseline ran {}, so they can't be compared", result.day, parts_name(&ran), parts_name(&baseline_ran))?;
+            continue;
+        }
+
+        for (phase, new) in summaries.iter().copied() {
+            let key = key(phase);
+            let Some(old) = baseline.summaries.get(&key) else {
+                writeln!(out, "Day {:2} {:>8}: not in the baseline", result.day, phase)?;
+                continue;
+            };
+
+            let change = if old.mean > 0. { (new.mean - old.mean) / old.mean * 100. } else { 0. };
+            let significant = significant(old, &new);
+            let verdict = if !significant {
+                ""
+            } else if change > threshold {
+                regressions.push(format!("day {} {}", result.day, phase.to_lowercase()));
+                "SLOWER"
+            } else if change < -threshold {
+                "faster"
+            } else {
+                ""
+            };
+            writeln!(
+                out,
+                "Day {:2} {:>8}: {:>8} -> {:>8} {:>+7.1}% {}",
+                result.day,
+                phase,
+                get_formatted_time(&Duration::from_nanos(old.mean.round() as u64)),
+                get_formatted_time(&Duration::from_nanos(new.mean.round() as u64)),
+                change,
+                verdict,
+            )?;
+        }
+    }
+
+    if regressions.is_empty() {
+        writeln!(out, "No regressions past {threshold}%")?;
+    } else {
+        writeln!(out, "Regressions [{}]: {}", regressions.len(), regressions.join(", "))?;
+    }
+    Ok(regressions.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Benchmark;
+    use crate::days::Person;
+    use crate::input::Input;
+
+    fn summary(samples: usize, mean: f64, stddev: f64) -> Summary {
+        Summary { samples, mean, stddev, median: mean }
+    }
+
+    #[test]
+    fn welch_t_test_separates_real_changes_from_noise() {
+        let old = summary(30, 1000., 10.);
+        // t is about 3.9 and 1.2 against a critical value of 1.96
+        assert!(significant(&old, &summary(30, 1010., 10.)));
+        assert!(!significant(&old, &summary(30, 1003., 10.)));
+        // Few samples need a bigger t: 2.45 is past 2.306 for 8 degrees of freedom but not 2.776 for 4
+        assert!(significant(&summary(5, 100., 1.), &summary(5, 101.55, 1.)));
+        assert!(!significant(&summary(3, 100., 1.), &summary(3, 102., 1.)));
+        // Without any noise every change counts, and one sample can't tell
+        assert!(significant(&summary(10, 100., 0.), &summary(10, 101., 0.)));
+        assert!(!significant(&summary(1, 100., 10.), &summary(30, 200., 10.)));
+    }
+
+    #[test]
+    fn partial_runs_have_no_total() {
+        let stats = Stats::from(&[Duration::from_micros(3), Duration::from_micros(4)]);
+        let benchmark = Benchmark { warmup_runs: 1, samples: 2, cut_short: false, parse: stats.clone(), prepare: stats.clone(), part1: Some(stats.clone()), part2: None, total: None };
+        let result = DayResult { benchmark: Some(benchmark), puzzle_input: true, ..DayResult::new(2024, Person::Aidan, 11, &Input::inline("125 17")) };
+        let phases = summaries(&result).into_iter().map(|x| x.0).collect::<Vec<&str>>();
+        assert_eq!(phases, ["Parsing", "Prepare", "Part 1"]);
+    }
+
+    #[test]
+    fn crashing_days_are_regressions() {
+        let stats = Stats::from(&[Duration::from_micros(3), Duration::from_micros(4)]);
+        let benchmark = Benchmark { warmup_runs: 1, samples: 2, cut_short: false, parse: stats.clone(), prepare: stats.clone(), part1: Some(stats.clone()), part2: Some(stats.clone()), total: Some(stats) };
+        let input = Input::inline("125 17");
+        let benchmarked = DayResult { benchmark: Some(benchmark), puzzle_input: true, ..DayResult::new(2024, Person::Aidan, 11, &input) };
+        let baseline = Baseline::from(&[benchmarked]);
+
+        let crashed = DayResult { part1: Answer::Crashed, part2: Answer::Crashed, crash: Some("bad input".to_owned()), puzzle_input: true, ..DayResult::new(2024, Person::Aidan, 11, &input) };
+        let mut out = vec![];
+        assert_eq!(compare(&baseline, &[crashed], 5., &mut out).unwrap(), 1);
+        assert!(String::from_utf8(out).unwrap().ends_with("Regressions [1]: day 11 crashed\n"));
+
+        // Days the baseline doesn't have can't have regressed
+        let other = DayResult { crash: Some("bad input".to_owned()), puzzle_input: true, ..DayResult::new(2024, Person::Aidan, 12, &input) };
+        assert_eq!(compare(&baseline, &[other], 5., &mut vec![]).unwrap(), 0);
+    }
+}
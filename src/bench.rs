@@ -0,0 +1,236 @@
+use std::time::{Duration, Instant};
+
+use crate::alloc::{AllocStats, PhaseAllocs, Tracker};
+use crate::days::{get_formatted_time, runs_part, solve_part, Answer, Solution};
+use crate::input::Input;
+
+// Never take fewer samples than this, even if the time budget runs out
+const MIN_SAMPLES: usize = 10;
+// Stop a timed benchmark even if there is time budget left
+const MAX_SAMPLES: usize = 1_000_000;
+
+pub enum Samples {
+    // Take exactly this many samples
+    Fixed(usize),
+    // Keep sampling until this much time has passed
+    Timed(Duration),
+}
+
+pub struct BenchConfig {
+    pub warmup: Duration,
+    pub samples: Samples,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub prepare: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    // All zero unless built with `--features alloc-stats`
+    pub allocs: PhaseAllocs,
+}
+
+impl PhaseTimes {
+    pub fn total(&self) -> Duration {
+        self.parse + self.prepare + self.part1 + self.part2
+    }
+}
+
+// Run and time one part, unless only the other part was asked for
+fn run_part(sol: &dyn Solution, number: usize, part: Option<usize>) -> (Answer, Duration, AllocStats) {
+    if !runs_part(part, number) {
+        return (Answer::Skipped, Duration::ZERO, AllocStats::default());
+    }
+    let tracker = Tracker::start();
+    let start = Instant::now();
+    let answer = solve_part(sol, number, part);
+    let elapsed = start.elapsed();
+    (answer, elapsed, tracker.finish())
+}
+
+// Run and time every phase once. Resetting the solution is not timed.
+pub fn run_once(sol: &mut Box<dyn Solution>, input: &str, part: Option<usize>) -> ((Answer, Answer), PhaseTimes) {
+    sol.reset();
+
+    let tracker = Tracker::start();
+    let start = Instant::now();
+    sol.parse_input(input);
+    let parse = start.elapsed();
+    let parse_allocs = tracker.finish();
+
+    let tracker = Tracker::start();
+    let start = Instant::now();
+    sol.prepare();
+    let prepare = start.elapsed();
+    let prepare_allocs = tracker.finish();
+
+    let (part1, part1_duration, part1_allocs) = run_part(sol.as_ref(), 1, part);
+    let (part2, part2_duration, part2_allocs) = run_part(sol.as_ref(), 2, part);
+
+    let allocs = PhaseAllocs { parse: parse_allocs, prepare: prepare_allocs, part1: part1_allocs, part2: part2_allocs };
+    ((part1, part2), PhaseTimes { parse, prepare, part1: part1_duration, part2: part2_duration, allocs })
+}
+
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    // Samples outside of the Tukey fences (1.5 IQR past the quartiles)
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty());
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let count = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (count - 1.)
+        } else {
+            0.
+        };
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let outliers = nanos.iter().filter(|x| **x < q1 - 1.5 * iqr || **x > q3 + 1.5 * iqr).count();
+
+        let as_duration = |x: f64| Duration::from_nanos(x.round() as u64);
+        Stats {
+            mean: as_duration(mean),
+            median: as_duration(percentile(&nanos, 0.5)),
+            p95: as_duration(percentile(&nanos, 0.95)),
+            stddev: as_duration(variance.sqrt()),
+            min: as_duration(nanos[0]),
+            max: as_duration(nanos[nanos.len() - 1]),
+            outliers,
+        }
+    }
+}
+
+// Linearly interpolated percentile of already sorted values
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let rank = fraction * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+pub struct Benchmark {
+    pub warmup_runs: usize,
+    pub samples: usize,
+    // Whether warming up or sampling stopped early because the day ran out of time
+    pub cut_short: bool,
+    pub parse: Stats,
+    pub prepare: Stats,
+    // Not set for a skipped part
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    // Not set when a part was skipped, since it couldn't be compared with the total of both
+    pub total: Option<Stats>,
+}
+
+// Past `deadline`, only the one sample needed for any stats at all is taken
+pub fn benchmark(sol: &mut Box<dyn Solution>, input: &str, part: Option<usize>, config: &BenchConfig, deadline: Option<Instant>) -> Benchmark {
+    let out_of_time = || deadline.is_some_and(|x| Instant::now() >= x);
+    let mut cut_short = false;
+
+    // Warm up caches and the branch predictor before measuring anything
+    let mut warmup_runs = 0;
+    let start = Instant::now();
+    while warmup_runs == 0 || start.elapsed() < config.warmup {
+        if out_of_time() {
+            cut_short = true;
+            break;
+        }
+        run_once(sol, input, part);
+        warmup_runs += 1;
+    }
+
+    let mut samples: Vec<PhaseTimes> = vec![];
+    let start = Instant::now();
+    loop {
+        if !samples.is_empty() && out_of_time() {
+            cut_short = true;
+            break;
+        }
+        let done = match config.samples {
+            Samples::Fixed(count) => samples.len() >= count.max(1),
+            Samples::Timed(budget) => samples.len() >= MAX_SAMPLES
+                || (samples.len() >= MIN_SAMPLES && start.elapsed() >= budget),
+        };
+        if done {
+            break;
+        }
+        let (_, times) = run_once(sol, input, part);
+        samples.push(times);
+    }
+
+    let phase = |f: fn(&PhaseTimes) -> Duration| Stats::from(&samples.iter().map(f).collect::<Vec<Duration>>());
+    Benchmark {
+        warmup_runs,
+        samples: samples.len(),
+        cut_short,
+        parse: phase(|x| x.parse),
+        prepare: phase(|x| x.prepare),
+        part1: runs_part(part, 1).then(|| phase(|x| x.part1)),
+        part2: runs_part(part, 2).then(|| phase(|x| x.part2)),
+        total: part.is_none().then(|| phase(|x| x.total())),
+    }
+}
+
+pub fn run_benchmark(sol: &mut Box<dyn Solution>, input: &Input, part: Option<usize>, config: &BenchConfig, deadline: Option<Instant>) -> Benchmark {
+    benchmark(sol, &input.read(), part, config, deadline)
+}
+
+pub fn print_benchmark(result: &Benchmark) {
+    println!("Benchmark: {} samples after {} warmup runs{}", result.samples, result.warmup_runs, if result.cut_short { ", cut short by --timeout" } else { "" });
+    println!("|          {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}", "mean", "median", "p95", "stddev", "min", "max", "outliers");
+    for (name, stats) in [("Parsing", Some(&result.parse)), ("Prepare", Some(&result.prepare)), ("Part 1", result.part1.as_ref()), ("Part 2", result.part2.as_ref()), ("Total", result.total.as_ref())] {
+        let Some(stats) = stats else {
+            println!("| {:>8}: skipped", name);
+            continue;
+        };
+        println!(
+            "| {:>8}: {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+            name,
+            get_formatted_time(&stats.mean),
+            get_formatted_time(&stats.median),
+            get_formatted_time(&stats.p95),
+            get_formatted_time(&stats.stddev),
+            get_formatted_time(&stats.min),
+            get_formatted_time(&stats.max),
+            stats.outliers,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_interpolate_percentiles_and_count_outliers() {
+        let mut samples = (1..=10).map(|x| Duration::from_nanos(x * 10)).collect::<Vec<Duration>>();
+        samples.push(Duration::from_nanos(1000));
+        let stats = Stats::from(&samples);
+        assert_eq!(stats.median, Duration::from_nanos(60));
+        // Halfway between the 10th and 11th samples
+        assert_eq!(stats.p95, Duration::from_nanos(550));
+        assert_eq!(stats.mean, Duration::from_nanos(141));
+        assert_eq!((stats.min, stats.max), (Duration::from_nanos(10), Duration::from_nanos(1000)));
+        // The fences are 35 - 75 and 85 + 75, so only the 1000 is out
+        assert_eq!(stats.outliers, 1);
+
+        let single = Stats::from(&[Duration::from_nanos(7)]);
+        assert_eq!((single.median, single.p95, single.stddev, single.outliers), (Duration::from_nanos(7), Duration::from_nanos(7), Duration::ZERO, 0));
+    }
+}
@@ -0,0 +1,136 @@
+use std::{env, io::Write, sync::OnceLock, thread, time::Duration};
+
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+
+use crate::days::{LAST_DAY, YEARS};
+
+// Overrides the current time for testing, as an RFC 3339 timestamp like `2024-12-01T04:59:50Z`
+pub const NOW_ENV_VAR: &str = "AOC_NOW";
+
+// Puzzles unlock at midnight UTC-5, wherever you are
+fn unlock_time_zone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+// The current time, or the one from `AOC_NOW`. An overridden clock keeps ticking so waiting still works.
+pub fn now() -> DateTime<Utc> {
+    static STARTED: OnceLock<DateTime<Utc>> = OnceLock::new();
+    let started = *STARTED.get_or_init(Utc::now);
+
+    let Ok(value) = env::var(NOW_ENV_VAR) else {
+        return Utc::now();
+    };
+    match DateTime::parse_from_rfc3339(&value) {
+        Ok(overridden) => overridden.with_timezone(&Utc) + (Utc::now() - started),
+        Err(_) => {
+            println!("{NOW_ENV_VAR} must be an RFC 3339 timestamp like 2024-12-01T04:59:50Z, not '{value}'");
+            std::process::exit(1);
+        }
+    }
+}
+
+// The latest puzzle of `year` that has unlocked by `now`
+pub fn unlocked_day(year: usize, now: DateTime<Utc>) -> Option<usize> {
+    let now = now.with_timezone(&unlock_time_zone());
+    let current_year = now.year() as usize;
+    if year < current_year {
+        Some(LAST_DAY)
+    } else if year == current_year && now.month() == 12 {
+        Some((now.day() as usize).min(LAST_DAY))
+    } else {
+        None
+    }
+}
+
+// (year, day, when) of the next puzzle to unlock after `now`
+pub fn next_unlock(now: DateTime<Utc>) -> (usize, usize, DateTime<Utc>) {
+    let now = now.with_timezone(&unlock_time_zone());
+    let (year, day) = match (now.month(), now.day() as usize) {
+        (12, day) if day < LAST_DAY => (now.year(), day + 1),
+        (12, _) => (now.year() + 1, 1),
+        _ => (now.year(), 1),
+    };
+    let when = unlock_time_zone().with_ymd_and_hms(year, 12, day as u32, 0, 0, 0).unwrap();
+    (year as usize, day, when.with_timezone(&Utc))
+}
+
+pub fn format_countdown(remaining: chrono::TimeDelta) -> String {
+    let seconds = remaining.num_seconds().max(0);
+    let (days, hours, minutes, seconds) = (seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+pub fn get_today(year: usize) -> Option<usize> {
+    unlocked_day(year, now())
+}
+
+// Explains why there's no day to default to
+pub fn no_today_message(year: usize) -> String {
+    let (next_year, next_day, when) = next_unlock(now());
+    if next_year == year {
+        format!("Day {next_day} of {year} unlocks in {}. Must set `--day`.", format_countdown(when - now()))
+    } else {
+        format!("Nothing of {year} has unlocked. Must set `--day`.")
+    }
+}
+
+// The latest year with solutions, or the current one once its Advent of Code has started
+pub fn get_default_year() -> usize {
+    let now = now().with_timezone(&unlock_time_zone());
+    let latest = YEARS.last().copied().unwrap_or(now.year() as usize);
+    if now.month() == 12 {
+        latest.max(now.year() as usize)
+    } else {
+        latest
+    }
+}
+
+// Count down to the next puzzle, then return its (year, day)
+pub fn wait_for_next_unlock() -> (usize, usize) {
+    let (year, day, when) = next_unlock(now());
+    loop {
+        let remaining = when - now();
+        if remaining <= chrono::TimeDelta::zero() {
+            println!("\rDay {day} of {year} is unlocked!          ");
+            return (year, day);
+        }
+        print!("\rDay {day} of {year} unlocks in {}    ", format_countdown(remaining));
+        let _ = std::io::stdout().flush();
+        thread::sleep(remaining.to_std().unwrap_or_default().min(Duration::from_secs(1)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn unlocks_at_midnight_utc_minus_5() {
+        assert_eq!(unlocked_day(2024, at("2024-12-01T04:59:59Z")), None);
+        assert_eq!(unlocked_day(2024, at("2024-12-01T05:00:00Z")), Some(1));
+        // Still the 9th in UTC-5, even though it's the 10th in UTC
+        assert_eq!(unlocked_day(2024, at("2024-12-10T03:00:00Z")), Some(9));
+    }
+
+    #[test]
+    fn caps_at_the_last_day() {
+        assert_eq!(unlocked_day(2024, at("2024-12-30T12:00:00Z")), Some(25));
+        assert_eq!(unlocked_day(2023, at("2024-06-01T12:00:00Z")), Some(25));
+        assert_eq!(unlocked_day(2024, at("2024-06-01T12:00:00Z")), None);
+    }
+
+    #[test]
+    fn finds_the_next_unlock() {
+        assert_eq!(next_unlock(at("2024-12-05T04:00:00Z")), (2024, 5, at("2024-12-05T05:00:00Z")));
+        assert_eq!(next_unlock(at("2024-12-26T12:00:00Z")), (2025, 1, at("2025-12-01T05:00:00Z")));
+        assert_eq!(next_unlock(at("2024-07-04T12:00:00Z")), (2024, 1, at("2024-12-01T05:00:00Z")));
+    }
+}
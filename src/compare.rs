@@ -0,0 +1,143 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use clap::ValueEnum;
+
+use crate::bench::{benchmark, run_once, BenchConfig};
+use crate::crash::catch_panic;
+use crate::days::{get_formatted_time, get_solutions, Answer, Person};
+use crate::input::Input;
+
+// What one person's solution did with the shared input
+struct Entry {
+    answers: [Answer; 2],
+    // The mean total when benchmarked, otherwise a single run. Not set if it crashed.
+    time: Option<Duration>,
+    crash: Option<String>,
+}
+
+impl Entry {
+    fn stars(&self) -> usize {
+        self.answers.iter().filter(|x| x.is_complete()).count()
+    }
+}
+
+pub fn everyone() -> Vec<Person> {
+    Person::value_variants().to_vec()
+}
+
+// The days every person has registered, in order
+pub fn common_days(year: usize, people: &[Person]) -> Vec<usize> {
+    let mut days: Option<BTreeSet<usize>> = None;
+    for person in people {
+        let registered = get_solutions(year, person.clone()).into_keys().collect::<BTreeSet<usize>>();
+        days = Some(match days {
+            Some(days) => days.intersection(&registered).copied().collect(),
+            None => registered,
+        });
+    }
+    days.unwrap_or_default().into_iter().collect()
+}
+
+fn agreement(answers: &[&Answer]) -> (&'static str, bool) {
+    if answers.iter().all(|x| !x.is_applicable()) {
+        ("-", false)
+    } else if answers.iter().any(|x| !x.is_complete()) {
+        ("incomplete", false)
+    } else if answers.windows(2).all(|x| x[0] == x[1]) {
+        ("agree", false)
+    } else {
+        ("DISAGREE", true)
+    }
+}
+
+// Run every person's solution for each day on the same input and compare them side by side.
+// Returns false if any answers disagree, because then at least one of them is wrong, if a solution crashed, or if there were no days.
+pub fn compare_people(year: usize, people: &[Person], days: &[(usize, Input)], bench_config: Option<&BenchConfig>) -> bool {
+    // Agreeing on nothing isn't a pass
+    if days.is_empty() {
+        println!("None of the days could be compared");
+        return false;
+    }
+    let mut totals = vec![Duration::ZERO; people.len()];
+    let mut wins = vec![0; people.len()];
+    let mut disagreements = vec![];
+    let mut crashes = vec![];
+    // Everyone's days are only built once, then reset for every input
+    let mut solutions = people.iter().map(|x| get_solutions(year, x.clone())).collect::<Vec<_>>();
+
+    let header = people.iter().map(|x| format!("{:>16}", x.name())).collect::<String>();
+    for (day_number, input) in days {
+        if !input.exists() {
+            println!("Day {day_number:2}: skipped, missing {} ({})", input.path, input.source);
+            continue;
+        }
+        let contents = input.read();
+
+        let entries = solutions.iter_mut().map(|registered| {
+            let sol = registered.get_mut(day_number).unwrap();
+            // One person's broken solution shouldn't stop the comparison
+            let ran = catch_panic(|| {
+                let ((part1, part2), times) = run_once(sol, &contents, None);
+                let time = match bench_config {
+                    // Both parts run, so there's always a total
+                    Some(config) => benchmark(sol, &contents, None, config, None).total.unwrap().mean,
+                    None => times.total(),
+                };
+                ([part1, part2], time)
+            });
+            match ran {
+                Ok((answers, time)) => Entry { answers, time: Some(time), crash: None },
+                Err(crash) => Entry { answers: [Answer::Crashed, Answer::Crashed], time: None, crash: Some(crash) },
+            }
+        }).collect::<Vec<Entry>>();
+
+        println!("Day {day_number} with {} ({}):", input.path, input.source);
+        println!("|         {header}");
+        for part in 0..2 {
+            let answers = entries.iter().map(|x| &x.answers[part]).collect::<Vec<&Answer>>();
+            let (verdict, disagree) = agreement(&answers);
+            if disagree {
+                disagreements.push(format!("day {day_number} part {}", part + 1));
+            }
+            let columns = answers.iter().map(|x| format!("{:>16}", x.to_string())).collect::<String>();
+            println!("|  Part {}:{columns}  {verdict}", part + 1);
+        }
+
+        for (person, entry) in people.iter().zip(&entries) {
+            if let Some(crash) = &entry.crash {
+                println!("|   Crash: {} {crash}", person.name());
+                crashes.push(format!("day {day_number} ({})", person.name()));
+            }
+        }
+
+        // The fastest of whoever earned the most stars
+        let most_stars = entries.iter().map(|x| x.stars()).max().unwrap_or(0);
+        let winner = (0..people.len())
+            .filter(|idx| entries[*idx].stars() == most_stars)
+            .filter_map(|idx| Some((idx, entries[idx].time?)))
+            .min_by_key(|x| x.1)
+            .map(|x| x.0);
+        if let Some(winner) = winner {
+            wins[winner] += 1;
+        }
+        for (total, entry) in totals.iter_mut().zip(&entries) {
+            *total += entry.time.unwrap_or_default();
+        }
+        let columns = entries.iter().map(|x| format!("{:>16}", x.time.map_or("-".to_owned(), |x| get_formatted_time(&x)))).collect::<String>();
+        println!("|    Time:{columns}  {} wins", winner.map_or("nobody", |x| people[x].name()));
+        println!();
+    }
+
+    println!("          {header}");
+    println!("   Total: {}", totals.iter().map(|x| format!("{:>16}", get_formatted_time(x))).collect::<String>());
+    println!("    Wins: {}", wins.iter().map(|x| format!("{x:>16}")).collect::<String>());
+    if disagreements.is_empty() {
+        println!("All answers agree");
+    } else {
+        println!("DISAGREEMENTS [{}]: {}", disagreements.len(), disagreements.join(", "));
+    }
+    if !crashes.is_empty() {
+        println!("CRASHES [{}]: {}", crashes.len(), crashes.join(", "));
+    }
+    disagreements.is_empty() && crashes.is_empty()
+}
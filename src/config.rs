@@ -0,0 +1,197 @@
+// Defaults for the command line, so they don't need to be given every time.
+// Each setting comes from the first of: its flag, its `AOC_*` environment variable, the project's `.aoc.toml`,
+// the user's `~/.config/aoc/config.toml`, and finally the built-in default.
+
+use std::{env, fs, path::{Path, PathBuf}};
+
+use clap::ValueEnum;
+
+use crate::days::Person;
+use crate::output::OutputFormat;
+
+pub const PROJECT_CONFIG_FILE_PATH: &str = ".aoc.toml";
+
+// Where the last person used to be kept, before there was a config file
+const LEGACY_LAST_PERSON_FILE_PATH: &str = ".last";
+
+#[derive(Debug, Default)]
+pub struct Config {
+    pub person: Option<Person>,
+    pub year: Option<usize>,
+    // The directory holding every input, instead of `data`
+    pub input_root: Option<String>,
+    // Take exactly this many benchmark samples instead of sampling for a while
+    pub bench_samples: Option<usize>,
+    pub format: Option<OutputFormat>,
+    // In seconds, like `--timeout`
+    pub timeout: Option<f64>,
+}
+
+// (key, environment variable)
+const KEYS: [(&str, &str); 6] = [
+    ("person", "AOC_PERSON"),
+    ("year", "AOC_YEAR"),
+    ("input_root", "AOC_INPUT_ROOT"),
+    ("bench_samples", "AOC_BENCH_SAMPLES"),
+    ("format", "AOC_FORMAT"),
+    ("timeout", "AOC_TIMEOUT"),
+];
+
+pub fn user_config_path() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => Some(PathBuf::from(dir).join("aoc/config.toml")),
+        None => env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/aoc/config.toml")),
+    }
+}
+
+// Values are written like TOML, with or without quotes
+fn unquote(value: &str) -> &str {
+    value.strip_prefix('"').and_then(|x| x.strip_suffix('"')).unwrap_or(value)
+}
+
+impl Config {
+    // Set `key` from `value`, saying what's wrong with it otherwise
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "person" => {
+                let names = Person::value_variants().iter().map(|x| x.name()).collect::<Vec<&str>>().join(" or ");
+                self.person = Some(Person::from_str(value, true).map_err(|_| format!("must be {names}, not '{value}'"))?);
+            }
+            "year" => self.year = Some(value.parse().map_err(|_| format!("must be a year like 2024, not '{value}'"))?),
+            "input_root" => self.input_root = Some(value.to_owned()),
+            "bench_samples" => match value.parse() {
+                Ok(samples) if samples > 0 => self.bench_samples = Some(samples),
+                _ => return Err(format!("must be a number of samples above 0, not '{value}'")),
+            },
+            "format" => self.format = Some(OutputFormat::from_str(value, true).map_err(|_| format!("must be text, json or csv, not '{value}'"))?),
+            "timeout" => match value.parse::<f64>() {
+                Ok(seconds) if seconds > 0.0 => self.timeout = Some(seconds),
+                _ => return Err(format!("must be a number of seconds above 0, not '{value}'")),
+            },
+            _ => {
+                let keys = KEYS.map(|(key, _)| key).join(", ");
+                return Err(format!("is not a setting, expected one of {keys}"));
+            }
+        }
+        Ok(())
+    }
+
+    // Apply the settings in a config file, if it exists
+    fn apply_file(&mut self, filepath: &Path) -> Result<(), String> {
+        if !filepath.exists() {
+            return Ok(());
+        }
+        let contents = fs::read_to_string(filepath).map_err(|e| format!("Failed to read {}: {e}", filepath.display()))?;
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("{}:{}: expected `key = value`, got '{line}'", filepath.display(), idx + 1));
+            };
+            let key = key.trim();
+            self.set(key, unquote(value.trim())).map_err(|e| format!("{}:{}: `{key}` {e}", filepath.display(), idx + 1))?;
+        }
+        Ok(())
+    }
+
+    fn apply_env(&mut self) -> Result<(), String> {
+        for (key, var) in KEYS {
+            if let Ok(value) = env::var(var) {
+                self.set(key, &value).map_err(|e| format!("{var} (for `{key}`) {e}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    // Everything but the command line, which the caller puts on top
+    pub fn load() -> Result<Config, String> {
+        let mut config = Config::default();
+        if let Some(filepath) = user_config_path() {
+            config.apply_file(&filepath)?;
+        }
+        config.apply_file(Path::new(PROJECT_CONFIG_FILE_PATH))?;
+        config.apply_env()?;
+        Ok(config)
+    }
+}
+
+// Set `key` in the config file at `filepath`, keeping everything else in it as it is
+pub fn save_setting(filepath: &Path, key: &str, value: &str) -> Result<(), String> {
+    let contents = if filepath.exists() {
+        fs::read_to_string(filepath).map_err(|e| format!("Failed to read {}: {e}", filepath.display()))?
+    } else {
+        String::new()
+    };
+
+    let setting = format!("{key} = \"{value}\"");
+    let mut found = false;
+    let mut lines = contents.lines().map(|line| {
+        if line.split_once('=').is_some_and(|(x, _)| x.trim() == key) {
+            found = true;
+            setting.clone()
+        } else {
+            line.to_owned()
+        }
+    }).collect::<Vec<String>>();
+    if !found {
+        lines.push(setting);
+    }
+    fs::write(filepath, lines.join("\n") + "\n").map_err(|e| format!("Failed to write {}: {e}", filepath.display()))
+}
+
+// Move the person from `.last` into the project's config file
+pub fn migrate_last_person() -> Result<Option<Person>, String> {
+    let Ok(last_person) = fs::read_to_string(LEGACY_LAST_PERSON_FILE_PATH) else {
+        return Ok(None);
+    };
+    let Ok(person) = Person::from_str(last_person.trim(), true) else {
+        return Ok(None);
+    };
+    save_setting(Path::new(PROJECT_CONFIG_FILE_PATH), "person", person.name())?;
+    let _ = fs::remove_file(LEGACY_LAST_PERSON_FILE_PATH);
+    Ok(Some(person))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let filepath = env::temp_dir().join(format!("aoc2024-config-{name}-{}.toml", std::process::id()));
+        fs::write(&filepath, contents).unwrap();
+        filepath
+    }
+
+    #[test]
+    fn reads_every_setting() {
+        let filepath = temp_file("every", "# defaults\nperson = \"will\"\nyear = 2023\ninput_root = \"inputs\"\n\nbench_samples = 50\nformat = json\ntimeout = 2.5\n");
+        let mut config = Config::default();
+        config.apply_file(&filepath).unwrap();
+        assert!(matches!(config.person, Some(Person::Will)));
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.input_root.as_deref(), Some("inputs"));
+        assert_eq!(config.bench_samples, Some(50));
+        assert!(matches!(config.format, Some(OutputFormat::Json)));
+        assert_eq!(config.timeout, Some(2.5));
+    }
+
+    #[test]
+    fn names_the_file_and_key_that_are_wrong() {
+        let filepath = temp_file("wrong", "person = aidan\nyear = soon\n");
+        let e = Config::default().apply_file(&filepath).unwrap_err();
+        assert_eq!(e, format!("{}:2: `year` must be a year like 2024, not 'soon'", filepath.display()));
+
+        let filepath = temp_file("unknown", "colour = blue\n");
+        let e = Config::default().apply_file(&filepath).unwrap_err();
+        assert!(e.starts_with(&format!("{}:1: `colour` is not a setting", filepath.display())), "{e}");
+    }
+
+    #[test]
+    fn saving_keeps_the_other_settings() {
+        let filepath = temp_file("save", "# mine\nperson = \"aidan\"\nyear = 2024\n");
+        save_setting(&filepath, "person", "will").unwrap();
+        assert_eq!(fs::read_to_string(&filepath).unwrap(), "# mine\nperson = \"will\"\nyear = 2024\n");
+    }
+}
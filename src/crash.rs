@@ -0,0 +1,56 @@
+use std::{cell::{Cell, RefCell}, panic::{self, AssertUnwindSafe, PanicHookInfo}, sync::Once};
+
+thread_local! {
+    // Whether a panic on this thread is going to be caught by `catch_panic`
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static CAUGHT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn describe(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "panicked".to_owned(),
+    };
+    match info.location() {
+        Some(location) => format!("{message} at {}:{}:{}", location.file(), location.line(), location.column()),
+        None => message,
+    }
+}
+
+// Caught panics are reported with their day instead of printed as they happen. Any other panic is printed as usual.
+fn install_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                CAUGHT.set(Some(describe(info)));
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+// Run `f`, turning a panic into its message and location so one broken day doesn't take down the rest
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+    CATCHING.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(false);
+    result.map_err(|_| CAUGHT.take().unwrap_or("panicked".to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_the_message_and_location() {
+        let crash = catch_panic(|| -> usize { panic!("bad input") }).unwrap_err();
+        assert!(crash.starts_with("bad input at src/crash.rs:"), "{crash}");
+        assert_eq!(catch_panic(|| 7), Ok(7));
+    }
+}
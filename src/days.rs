@@ -1,170 +1,272 @@
-use std::{collections::HashMap, fs, time::{Duration, SystemTime}};
-use chrono::Datelike;
+use std::{cell::Cell, collections::HashMap, fmt, fs, sync::mpsc, thread, time::{Duration, Instant}};
 
-pub trait Solution {
+use crate::input::Input;
+use crate::output::DayResult;
+
+// `Send` so `run -a --jobs` can solve days on other threads
+pub trait Solution: Send {
     fn reset(&mut self);
     fn parse_input(&mut self, file_contents: &str);
-    fn part1(&self) -> String;
-    fn part2(&self) -> String;
+    // Work both parts need, like a search whose result they both read. Timed on its own.
+    fn prepare(&mut self) {}
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
 }
 
-mod aidan;
-mod will;
-
-#[derive(Clone, Debug, clap::ValueEnum)]
-pub enum Person {
-    Aidan,
-    Will
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    // The part hasn't been solved yet
+    NotImplemented,
+    // There is nothing to solve, like the second part of day 25
+    NotApplicable,
+    // The solution ran but couldn't find an answer
+    Failed,
+    // Only the other part was asked for
+    Skipped,
+    // The day ran past its `--timeout`
+    TimedOut,
+    // The day panicked before this part had an answer
+    Crashed,
 }
 
-// -----------------------------------------------
+impl Answer {
+    // Whether this answer earns a star
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Answer::Integer(_) | Answer::Text(_))
+    }
+
+    // Whether this part can earn a star at all
+    pub fn is_applicable(&self) -> bool {
+        !matches!(self, Answer::NotApplicable | Answer::Skipped)
+    }
 
-pub fn get_solutions(by: Person) -> HashMap<usize, Box<dyn Solution>> {
-    let mut result: HashMap<usize, Box<dyn Solution>> = HashMap::new();
-    match by {
-        Person::Will => {
-            // ADD_SOLUTION_HERE
+    // Read back an answer that was written with `Display`
+    pub fn parse(value: &str) -> Answer {
+        match value {
+            "Not Implemented" => Answer::NotImplemented,
+            "Not Applicable" => Answer::NotApplicable,
+            "Failed" => Answer::Failed,
+            "Skipped" => Answer::Skipped,
+            "Timed Out" => Answer::TimedOut,
+            "Crashed" => Answer::Crashed,
+            _ => match value.parse::<i64>() {
+                Ok(x) => Answer::Integer(x),
+                Err(_) => Answer::Text(value.to_owned()),
+            }
         }
-        Person::Aidan => {
-            result.insert(1, Box::new(aidan::day1::Day1::new()));
-            result.insert(3, Box::new(aidan::day3::Day3::new()));
-            result.insert(4, Box::new(aidan::day4::Day4::new()));
-            result.insert(5, Box::new(aidan::day5::Day5::new()));
-            result.insert(6, Box::new(aidan::day6::Day6::new()));
-            result.insert(7, Box::new(aidan::day7::Day7::new()));
-            result.insert(8, Box::new(aidan::day8::Day8::new()));
-            result.insert(9, Box::new(aidan::day9::Day9::new()));
-            result.insert(10, Box::new(aidan::day10::Day10::new()));
-            result.insert(11, Box::new(aidan::day11::Day11::new()));
-            result.insert(12, Box::new(aidan::day12::Day12::new()));
-            result.insert(13, Box::new(aidan::day13::Day13::new()));
-            result.insert(14, Box::new(aidan::day14::Day14::new()));
-            result.insert(15, Box::new(aidan::day15::Day15::new()));
-            result.insert(16, Box::new(aidan::day16::Day16::new()));
-            result.insert(17, Box::new(aidan::day17::Day17::new()));
-            result.insert(18, Box::new(aidan::day18::Day18::new()));
-            result.insert(19, Box::new(aidan::day19::Day19::new()));
-            result.insert(20, Box::new(aidan::day20::Day20::new()));
-            result.insert(21, Box::new(aidan::day21::Day21::new()));
-            result.insert(22, Box::new(aidan::day22::Day22::new()));
-            result.insert(23, Box::new(aidan::day23::Day23::new()));
-            result.insert(24, Box::new(aidan::day24::Day24::new()));
-            result.insert(25, Box::new(aidan::day25::Day25::new()));
+    }
+
+    // A stable name for machine-readable output
+    pub fn state(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) | Answer::Text(_) => "complete",
+            Answer::NotImplemented => "not_implemented",
+            Answer::NotApplicable => "not_applicable",
+            Answer::Failed => "failed",
+            Answer::Skipped => "skipped",
+            Answer::TimedOut => "timed_out",
+            Answer::Crashed => "crashed",
+        }
+    }
+
+    pub fn star(&self) -> char {
+        if self.is_complete() {
+            '*'
+        } else if self.is_applicable() {
+            ' '
+        } else {
+            '-'
         }
     }
-    // -----------------
-    result
 }
 
-pub fn get_today() -> Option<usize> {
-    // Get the current day
-    let now = chrono::Local::now();
-    if now.month() == 12 {
-        Some(now.day() as usize)
-    } else {
-        None
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(x) => write!(f, "{x}"),
+            Answer::Text(x) => write!(f, "{x}"),
+            Answer::NotImplemented => write!(f, "Not Implemented"),
+            Answer::NotApplicable => write!(f, "Not Applicable"),
+            Answer::Failed => write!(f, "Failed"),
+            Answer::Skipped => write!(f, "Skipped"),
+            Answer::TimedOut => write!(f, "Timed Out"),
+            Answer::Crashed => write!(f, "Crashed"),
+        }
     }
 }
 
-// -----------------------------------------------
+macro_rules! answer_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(i64::try_from(value).expect("answer does not fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i32, i64, u32, u64, usize);
 
-fn read_file(filepath: &str) -> String {
-    fs::read_to_string(filepath)
-        .unwrap_or_else(|_| panic!("Failed to read data file: {}", filepath))
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
 }
 
-fn get_formatted_time(d: &Duration) -> String {
-    if d.as_secs() > 9 {
-        format!("{}s", d.as_secs())
-    } else if d.as_millis() > 9 {
-        format!("{}ms", d.as_millis())
-    } else {
-        format!("{}us", d.as_micros())
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
     }
 }
 
-pub fn run_many_times(sol: &mut Box<dyn Solution>, filepath: &str, times: usize) {
-    let input = read_file(filepath);
-    let mut average: Duration;
-    let mut min_time: Duration;
-    let mut max_time: Duration;
+// Declares the puzzle examples for a day next to its `Solution` impl. Every example becomes
+// a test that runs through the same path as the runner. Use `_` for a part without an example answer.
+macro_rules! examples {
+    ($solution:expr; $($name:ident: $input:expr => ($part1:tt, $part2:tt);)+) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+            $(
+                #[test]
+                fn $name() {
+                    crate::days::check_example(Box::new($solution), $input, [examples!(@expect $part1), examples!(@expect $part2)]);
+                }
+            )+
+        }
+    };
+    (@expect _) => { None };
+    (@expect $answer:tt) => { Some(crate::days::Answer::from($answer)) };
+}
 
-    // Run the first time
-    let start: SystemTime = SystemTime::now();
-    sol.reset();
-    sol.parse_input(&input);
-    let _ = sol.part1();
-    let _ = sol.part2();
-    let end = SystemTime::now();
-    average = end.duration_since(start).unwrap();
-    min_time = average;
-    max_time = average;
-
-    // Run many more times
-    for _ in 0..times-1 {
-        sol.reset();
-
-        let start: SystemTime = SystemTime::now();
-        sol.parse_input(&input);
-        let _ = sol.part1();
-        let _ = sol.part2();
-        let end = SystemTime::now();
-        let duration = end.duration_since(start).unwrap();
-
-        min_time = duration.min(min_time);
-        max_time = duration.max(max_time);
-
-        average += duration;
+// The last puzzle of every year. `build.rs` keeps its own copy since it can't use the crate.
+pub const LAST_DAY: usize = 25;
+
+// Parses `--day`, so every command accepts the same days
+pub fn day_parser() -> clap::builder::RangedU64ValueParser<usize> {
+    clap::builder::RangedU64ValueParser::<usize>::new().range(1..=LAST_DAY as u64)
+}
+
+#[derive(Clone, Debug, clap::ValueEnum)]
+pub enum Person {
+    Aidan,
+    Will
+}
+
+impl Person {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Person::Aidan => "aidan",
+            Person::Will => "will",
+        }
     }
+}
+
+// -----------------------------------------------
+
+// Generated by `build.rs` from every `days/<year>/<person>/dayN.rs`: a `yYEAR` module for each year, `YEARS` and `get_solutions`
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+// -----------------------------------------------
+
+pub fn read_file(filepath: &str) -> String {
+    let contents = fs::read_to_string(filepath)
+        .unwrap_or_else(|_| panic!("Failed to read data file: {}", filepath));
+    normalize_input(&contents)
+}
+
+// Solutions split on '\n' and don't expect blank lines around the input
+pub fn normalize_input(contents: &str) -> String {
+    contents.replace("\r\n", "\n").trim_matches('\n').to_owned()
+}
+
+fn load(sol: &mut Box<dyn Solution>, input: &str) {
+    sol.reset();
+    sol.parse_input(input);
+    sol.prepare();
+}
+
+// Whether to run `number` when only `part` was asked for. `None` runs both parts.
+pub fn runs_part(part: Option<usize>, number: usize) -> bool {
+    part.is_none_or(|x| x == number)
+}
 
-    average = average / times as u32;
+thread_local! {
+    // When the day running on this thread has to stop, set by `run_with_timeout`
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
 
-    println!("Average duration: {} ({}us)", get_formatted_time(&average), average.as_micros());
-    println!("Minimum duration: {} ({}us)", get_formatted_time(&min_time), min_time.as_micros());
-    println!("Maximum duration: {} ({}us)", get_formatted_time(&max_time), max_time.as_micros());
+// Long-running parts should check this every so often and give up once it's true
+pub fn past_deadline() -> bool {
+    DEADLINE.get().is_some_and(|deadline| Instant::now() >= deadline)
 }
 
-pub fn print_answers(leading: String, sol: &mut Box<dyn Solution>, filepath: &str) -> usize {
-    let (part1, part2) = get_answer(sol, filepath);
-    let completed1 = part1 != "Not Implemented";
-    let completed2 = part2 != "Not Implemented";
-    println!("{} [{}] [{}] => {}, {}", leading, if completed1 { '*' } else { ' ' }, if completed2 { '*' } else { ' ' }, part1, part2);
-    (completed1 as u8 + completed2 as u8) as usize
+// Run `f` on its own thread, giving it `timeout` before its parts count as timed out.
+// Returns None if it didn't even give up in time, leaving it running in the background.
+pub fn run_with_timeout<T: Send + 'static>(timeout: Duration, f: impl FnOnce() -> T + Send + 'static) -> Option<T> {
+    // How long to wait for a part that checks its deadline to notice
+    const GRACE: Duration = Duration::from_millis(500);
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        DEADLINE.set(Some(Instant::now() + timeout));
+        let _ = sender.send(f());
+    });
+    receiver.recv_timeout(timeout + GRACE).ok()
 }
 
-fn get_answer(sol: &mut Box<dyn Solution>, filepath: &str) -> (String, String) {
-    let input = read_file(filepath);
-    sol.parse_input(&input);
-    let part1 = sol.part1();
-    let part2 = sol.part2();
-    (part1, part2)
+// Run one part, unless only the other part was asked for or the day is out of time
+pub fn solve_part(sol: &dyn Solution, number: usize, part: Option<usize>) -> Answer {
+    if !runs_part(part, number) {
+        return Answer::Skipped;
+    }
+    if past_deadline() {
+        return Answer::TimedOut;
+    }
+    let answer = if number == 1 { sol.part1() } else { sol.part2() };
+    // Whatever a part returns after giving up isn't its answer
+    if past_deadline() { Answer::TimedOut } else { answer }
 }
 
-pub fn run_day(sol: &mut Box<dyn Solution>, filepath: &str) {
-    let input = read_file(filepath);
-    // Time every part of this
-    let start: SystemTime = SystemTime::now();
-    sol.parse_input(&input);
-    let end = SystemTime::now();
-    let parse_duration = end.duration_since(start).unwrap();
+pub fn solve(sol: &mut Box<dyn Solution>, input: &str, part: Option<usize>) -> (Answer, Answer) {
+    load(sol, input);
+    (solve_part(sol.as_ref(), 1, part), solve_part(sol.as_ref(), 2, part))
+}
 
-    let start: SystemTime = SystemTime::now();
-    let part1 = sol.part1();
-    let end = SystemTime::now();
-    let part1_duration = end.duration_since(start).unwrap();
+#[cfg(test)]
+pub fn check_example(mut sol: Box<dyn Solution>, input: &str, expected: [Option<Answer>; 2]) {
+    load(&mut sol, &normalize_input(input));
+    if let Some(expected) = &expected[0] {
+        assert_eq!(sol.part1(), *expected, "part 1");
+    }
+    if let Some(expected) = &expected[1] {
+        assert_eq!(sol.part2(), *expected, "part 2");
+    }
+}
 
-    let start: SystemTime = SystemTime::now();
-    let part2 = sol.part2();
-    let end = SystemTime::now();
-    let part2_duration = end.duration_since(start).unwrap();
+pub fn get_formatted_time(d: &Duration) -> String {
+    if d.as_secs() > 9 {
+        format!("{}s", d.as_secs())
+    } else if d.as_millis() > 9 {
+        format!("{}ms", d.as_millis())
+    } else if d.as_micros() > 9 {
+        format!("{}us", d.as_micros())
+    } else {
+        format!("{}ns", d.as_nanos())
+    }
+}
 
-    let total_time = parse_duration + part1_duration + part2_duration;
+pub fn get_answer(sol: &mut Box<dyn Solution>, input: &Input, part: Option<usize>) -> (Answer, Answer) {
+    solve(sol, &input.read(), part)
+}
 
-    // Report the results
-    println!("Part 1: {part1}");
-    println!("Part 2: {part2}");
-    println!("Duration: {} ({}us)", get_formatted_time(&total_time), total_time.as_micros());
-    println!("|   Parsing: {}", get_formatted_time(&parse_duration));
-    println!("|    Part 1: {}", get_formatted_time(&part1_duration));
-    println!("|    Part 2: {}", get_formatted_time(&part2_duration));
+// Run every phase once, timing each of them
+pub fn run_day(sol: &mut Box<dyn Solution>, input: &Input, part: Option<usize>, result: &mut DayResult) {
+    let ((part1, part2), times) = crate::bench::run_once(sol, &input.read(), part);
+    result.part1 = part1;
+    result.part2 = part2;
+    result.times = Some(times);
 }
@@ -1,7 +1,7 @@
 
 use std::collections::BTreeMap;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day1 {
@@ -45,7 +45,7 @@ impl Solution for Day1 {
         }
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut right = self.right.clone();
         let (mut least_right, mut count_right) = right.pop_first().unwrap();
 
@@ -67,19 +67,31 @@ impl Solution for Day1 {
                 total_distance += distance;
             }
         }
-        total_distance.to_string()
+        total_distance.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Calculate a total similarity score by adding up
         // each number in the left list after multiplying it
         // by the number of times that number appears in the right list.
         let mut similarity_score = 0;
-        for (value, _) in self.left.iter() {
+        for (value, count) in self.left.iter() {
             let appearances = self.right.get(value).unwrap_or(&0);
-            let score = value * appearances;
+            let score = value * appearances * count;
             similarity_score += score;
         }
-        similarity_score.to_string()
+        similarity_score.into()
     }
 }
+
+examples! {
+    Day1::new();
+    example: r"
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+" => (11, 31);
+}
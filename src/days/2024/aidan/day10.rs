@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day10 {
@@ -70,7 +70,7 @@ impl Solution for Day10 {
         self.zero_locations = zeroes;
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         // Starting from all zeroes, find the number of unique 9s accessible
         self.zero_locations.iter().map(|x| {
             let mut stack = vec![*x];
@@ -85,10 +85,10 @@ impl Solution for Day10 {
             stack.sort();
             stack.dedup();
             stack.len()
-        }).sum::<usize>().to_string()
+        }).sum::<usize>().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Starting from all zeroes, find the number of unique 9s accessible
         self.zero_locations.iter().map(|x| {
             let mut stack = vec![*x];
@@ -101,6 +101,20 @@ impl Solution for Day10 {
                 stack = next_stack;
             }
             stack.len()
-        }).sum::<usize>().to_string()
+        }).sum::<usize>().into()
     }
 }
+
+examples! {
+    Day10::new();
+    example: r"
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+" => (36, 81);
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug, Clone)]
 pub struct Day11 {
@@ -92,11 +92,16 @@ impl Solution for Day11 {
         }
     }
 
-    fn part1(&self) -> String {
-        self.blink(25).to_string()
+    fn part1(&self) -> Answer {
+        self.blink(25).into()
     }
 
-    fn part2(&self) -> String {
-        self.blink(75).to_string()
+    fn part2(&self) -> Answer {
+        self.blink(75).into()
     }
-}
\ No newline at end of file
+}
+
+examples! {
+    Day11::new();
+    example: r"125 17" => (55312, _);
+}
@@ -1,6 +1,6 @@
 use std::{collections::{BTreeMap, HashSet}, ops::{Not, Range}};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day12 {
@@ -133,7 +133,7 @@ impl Solution for Day12 {
         }).collect()
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut seen = HashSet::new();
        
         self.grid.iter().enumerate()
@@ -145,10 +145,10 @@ impl Solution for Day12 {
                 })
             })
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut seen = HashSet::new();
        
         self.grid.iter().enumerate()
@@ -160,6 +160,28 @@ impl Solution for Day12 {
                 })
             })
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 }
+
+examples! {
+    Day12::new();
+    example1: r"
+AAAA
+BBCD
+BBCC
+EEEC
+" => (140, 80);
+    example2: r"
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+" => (1930, 1206);
+}
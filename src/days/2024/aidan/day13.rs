@@ -1,4 +1,4 @@
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 /*
  * This day is just matrix multiplication.
@@ -180,7 +180,7 @@ impl Solution for Day13 {
         self.machines = file_contents.split("\n\n").map(|x| Machine::from(x).unwrap()).collect()
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut total = 0;
 
         for machine in &self.machines {
@@ -189,10 +189,10 @@ impl Solution for Day13 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut total = 0;
         const EXTRA: f64 = 10000000000000.;
 
@@ -203,6 +203,27 @@ impl Solution for Day13 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
-}
\ No newline at end of file
+}
+
+examples! {
+    Day13::new();
+    example: r"
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+" => (480, _);
+}
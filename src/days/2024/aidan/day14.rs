@@ -1,7 +1,8 @@
 
 use std::cmp::max;
 
-use crate::days::Solution;
+use crate::days::{past_deadline, Answer, Solution};
+use crate::visualize::{emit, Colour, Frame, Visualize};
 
 const SECONDS: usize = 100;
 const WIDTH: i64 = 101;
@@ -59,41 +60,63 @@ impl Robot {
     }
 }
 
+// The robots at one moment, for drawing
+struct Room<'a> {
+    robots: &'a [Robot],
+    width: i64,
+    height: i64,
+}
+
+impl Visualize for Room<'_> {
+    fn draw(&self) -> Frame {
+        let mut counts = vec![vec![0; self.width as usize]; self.height as usize];
+        for robot in self.robots {
+            counts[robot.y as usize][robot.x as usize] += 1;
+        }
+        let mut frame = Frame::new(self.width as usize, self.height as usize);
+        for (y, row) in counts.iter().enumerate() {
+            for (x, count) in row.iter().enumerate() {
+                match count {
+                    0 => frame.set(x, y, '.', Colour::Dark),
+                    1..=9 => frame.set(x, y, char::from_digit(*count, 10).unwrap(), Colour::Green),
+                    _ => frame.set(x, y, '+', Colour::Green),
+                }
+            }
+        }
+        frame
+    }
+}
+
 #[derive(Debug)]
 pub struct Day14 {
     // State generated by `parse_input`
-    robots: Vec<Robot>
+    robots: Vec<Robot>,
+    width: i64,
+    height: i64,
 }
 
 impl Day14 {
     // Needed for creating a blank day
     pub fn new() -> Day14 {
+        Day14::with_size(WIDTH, HEIGHT)
+    }
+
+    // The example uses a smaller room than the real input
+    pub fn with_size(width: i64, height: i64) -> Day14 {
         Day14 {
             robots: vec![],
+            width,
+            height,
         }
     }
 
-    fn _print_grid(robots: &Vec<Robot>) {
-        let mut grid: Vec<Vec<usize>> = vec![vec![0; WIDTH as usize]; HEIGHT as usize];
-        for robot in robots {
-            grid[robot.y as usize][robot.x as usize] += 1;
-        }
-        for line in grid {
-            for num in line {
-                if num == 0 {
-                    print!(".")
-                }
-                else {
-                    print!("{num}");
-                }
-            }
-            println!("");
-        }
+    fn room<'a>(&self, robots: &'a [Robot]) -> Room<'a> {
+        Room { robots, width: self.width, height: self.height }
     }
 
-    fn count_quads(robots: &Vec<Robot>) -> (usize, usize, usize, usize) {
-        const HORIZONTAL: i64 = WIDTH / 2;
-        const VERTICAL: i64 = HEIGHT / 2;
+    fn count_quads(&self, robots: &Vec<Robot>) -> (usize, usize, usize, usize) {
+        let horizontal = self.width / 2;
+        let vertical = self.height / 2;
         let mut quadrants = vec![0 as usize; 4];
         // +---+---+
         // | 0 | 1 |
@@ -101,13 +124,13 @@ impl Day14 {
         // | 2 | 3 |
         // +---+---+
         for robot in robots {
-            match robot.x.cmp(&HORIZONTAL) {
-                std::cmp::Ordering::Less => match robot.y.cmp(&VERTICAL) {
+            match robot.x.cmp(&horizontal) {
+                std::cmp::Ordering::Less => match robot.y.cmp(&vertical) {
                     std::cmp::Ordering::Less => {quadrants[0] += 1;}
                     std::cmp::Ordering::Greater => {quadrants[1] += 1;}
                     std::cmp::Ordering::Equal => {}
                 }
-                std::cmp::Ordering::Greater => match robot.y.cmp(&VERTICAL) {
+                std::cmp::Ordering::Greater => match robot.y.cmp(&vertical) {
                     std::cmp::Ordering::Less => {quadrants[2] += 1;}
                     std::cmp::Ordering::Greater => {quadrants[3] += 1;}
                     std::cmp::Ordering::Equal => {}
@@ -136,39 +159,43 @@ impl Solution for Day14 {
         }).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut robots = self.robots.clone();
         for robot in robots.iter_mut() {
             for _ in 0..SECONDS {
-                robot.x = (robot.x + robot.vx + WIDTH) % WIDTH;
-                robot.y = (robot.y + robot.vy + HEIGHT) % HEIGHT;
+                robot.x = (robot.x + robot.vx + self.width) % self.width;
+                robot.y = (robot.y + robot.vy + self.height) % self.height;
             }
         }
 
-        // Day14::print_grid(&robots);
+        emit(&self.room(&robots), format_args!("After {SECONDS} seconds"));
         // Count them by quadrant
-        let quadrants = Day14::count_quads(&robots);
+        let quadrants = self.count_quads(&robots);
         let result = quadrants.0 * quadrants.1 * quadrants.2 * quadrants.3;
-        result.to_string()
+        result.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Need a way of reducing the number we need to manually review.
         let mut robots = self.robots.clone();
         for second in 1..43081462 {
+            if past_deadline() {
+                return Answer::TimedOut;
+            }
             // We want to find one that has a continuous string of non-0s at the middle.
-            let mut middle_col = vec![0 as usize; HEIGHT as usize];
+            let mut middle_col = vec![0 as usize; self.height as usize];
             for robot in robots.iter_mut() {
-                robot.x = (robot.x + robot.vx + WIDTH) % WIDTH;
-                robot.y = (robot.y + robot.vy + HEIGHT) % HEIGHT;
-                if robot.y == WIDTH / 2 {
+                robot.x = (robot.x + robot.vx + self.width) % self.width;
+                robot.y = (robot.y + robot.vy + self.height) % self.height;
+                if robot.y == self.width / 2 {
                     middle_col[robot.x as usize] += 1;
                 }
             }
+            emit(&self.room(&robots), format_args!("Second {second}"));
             // See if we have consequtive ones!
             let mut streak = 0;
             let mut best_streak = 0;
-            for i in 1..HEIGHT as usize - 1 {
+            for i in 1..self.height as usize - 1 {
                 if middle_col[i - 1] > 0 && middle_col[i] > 0 {
                     streak += 1;
                 }
@@ -178,10 +205,28 @@ impl Solution for Day14 {
                 }
             }
             if best_streak >= 10 {
-                    return second.to_string();
+                    return second.into();
             }
         }
 
-        "FAILED!".to_string()
+        Answer::Failed
     }
 }
+
+examples! {
+    Day14::with_size(11, 7);
+    example: r"
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+" => (12, _);
+}
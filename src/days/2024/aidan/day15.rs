@@ -1,7 +1,8 @@
 
 use std::{collections::VecDeque};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
+use crate::visualize::{emit, Colour, Frame, Visualize};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 enum Direction {
@@ -29,6 +30,30 @@ impl Direction {
     }
 }
 
+// The warehouse between moves, for drawing
+struct Warehouse<'a> {
+    grid: &'a Vec<Vec<char>>,
+    robot: (usize, usize),
+}
+
+impl Visualize for Warehouse<'_> {
+    fn draw(&self) -> Frame {
+        let mut frame = Frame::new(self.grid.first().map_or(0, |x| x.len()), self.grid.len());
+        for (y, row) in self.grid.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                let colour = match c {
+                    '#' => Colour::Grey,
+                    'O' | '[' | ']' => Colour::Yellow,
+                    _ => Colour::Dark,
+                };
+                frame.set(x, y, *c, colour);
+            }
+        }
+        frame.set(self.robot.0, self.robot.1, '@', Colour::Red);
+        frame
+    }
+}
+
 #[derive(Debug)]
 pub struct Day15 {
     // State generated by `parse_input`
@@ -43,13 +68,6 @@ impl Day15 {
         Day15 { grid: vec![], movements: vec![], start: (0, 0) }
     }
 
-    #[allow(unused)]
-    fn print_grid(grid: &Vec<Vec<char>>) {
-        for row in grid {
-            println!("{}", row.iter().collect::<String>());
-        }
-    }
-
     fn sum_block_scores(grid: &Vec<Vec<char>>, search: char) -> usize {
         let mut total = 0;
         for (y, row) in grid.iter().enumerate() {
@@ -139,11 +157,12 @@ impl Solution for Day15 {
         self.grid[self.start.1][self.start.0] = '.';
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut grid = self.grid.clone();
         let mut position = self.start;
 
-        for dir in &self.movements {
+        for (idx, dir) in self.movements.iter().enumerate() {
+            emit(&Warehouse { grid: &grid, robot: position }, format_args!("After {idx} of {} moves", self.movements.len()));
             assert!(grid[position.1][position.0] == '.');
             // Try and move that way
             let next = dir.translate(&position);
@@ -178,10 +197,11 @@ impl Solution for Day15 {
             position = first_block_position;
         }
 
-        Self::sum_block_scores(&grid, 'O').to_string()
+        emit(&Warehouse { grid: &grid, robot: position }, format_args!("After all {} moves", self.movements.len()));
+        Self::sum_block_scores(&grid, 'O').into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Need to make the new grid
         let mut grid: Vec<Vec<char>> = self.grid.iter().map(|line| {
             let mut row = vec![];
@@ -197,7 +217,8 @@ impl Solution for Day15 {
         }).collect();
         let mut position = (self.start.0 * 2, self.start.1);
 
-        for dir in &self.movements {
+        for (idx, dir) in self.movements.iter().enumerate() {
+            emit(&Warehouse { grid: &grid, robot: position }, format_args!("After {idx} of {} moves", self.movements.len()));
             assert!(grid[position.1][position.0] == '.');
             // Try and move that way
             let next = dir.translate(&position);
@@ -281,6 +302,46 @@ impl Solution for Day15 {
             }
         }
 
-        Self::sum_block_scores(&grid, '[').to_string()
+        emit(&Warehouse { grid: &grid, robot: position }, format_args!("After all {} moves", self.movements.len()));
+        Self::sum_block_scores(&grid, '[').into()
     }
 }
+
+examples! {
+    Day15::new();
+    example1: r"
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+" => (2028, _);
+    example2: r"
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+" => (10092, 9021);
+}
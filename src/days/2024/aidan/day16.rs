@@ -1,6 +1,7 @@
 use std::{cmp::Reverse, collections::{HashMap, HashSet, VecDeque}};
 use priority_queue::PriorityQueue;
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
+use crate::visualize::{emit, Colour, Frame, Visualize};
 
 const INFINITY: usize = 10e10 as usize;
 
@@ -45,11 +46,13 @@ pub struct Day16 {
     grid: Vec<Vec<char>>,
     width: usize,
     height: usize,
+    // Generated by `prepare`
+    paths: Vec<Vec<(usize, usize)>>,
 }
 
 impl Day16 {
     pub fn new() -> Self {
-        Day16 { grid: vec![], width: 0, height: 0 }
+        Day16 { grid: vec![], width: 0, height: 0, paths: vec![] }
     }
 
     fn blocked(&self, x: usize, y: usize) -> bool {
@@ -57,11 +60,38 @@ impl Day16 {
     }
 }
 
+// The maze with some of its best paths, for drawing
+struct Paths<'a> {
+    day: &'a Day16,
+    paths: &'a [Vec<(usize, usize)>],
+}
+
+impl Visualize for Paths<'_> {
+    fn draw(&self) -> Frame {
+        let mut frame = Frame::new(self.day.width, self.day.height);
+        for (y, row) in self.day.grid.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                match c {
+                    '#' => frame.set(x, y, '#', Colour::Grey),
+                    _ => frame.set(x, y, '.', Colour::Dark),
+                }
+            }
+        }
+        for (x, y) in self.paths.iter().flatten() {
+            frame.set(*x, *y, 'O', Colour::Green);
+        }
+        frame.set(1, self.day.height - 2, 'S', Colour::Red);
+        frame.set(self.day.width - 2, 1, 'E', Colour::Red);
+        frame
+    }
+}
+
 impl Solution for Day16 {
     fn reset(&mut self) {
         self.grid.clear();
         self.width = 0;
         self.height = 0;
+        self.paths.clear();
     }
 
     fn parse_input(&mut self, contents: &str) {
@@ -70,18 +100,24 @@ impl Solution for Day16 {
         self.width = self.grid[0].len();
     }
    
-    fn part1(&self) -> String {
-        self.calculate_path_cost(self.shortest_paths().first().unwrap()).to_string()
+    fn prepare(&mut self) {
+        self.paths = self.shortest_paths();
+    }
+
+    fn part1(&self) -> Answer {
+        emit(&Paths { day: self, paths: &self.paths[..1] }, "One of the best paths");
+        self.calculate_path_cost(self.paths.first().unwrap()).into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
+        emit(&Paths { day: self, paths: &self.paths }, format_args!("All {} best paths", self.paths.len()));
         let mut on_best_path = HashSet::new();
-        for path in self.shortest_paths() {
+        for path in &self.paths {
             for visited in path {
                 on_best_path.insert(visited);
             }
         }
-        on_best_path.len().to_string()
+        on_best_path.len().into()
     }
 }
 
@@ -222,3 +258,43 @@ impl Day16 {
         cost
     }
 }
+
+examples! {
+    Day16::new();
+    example1: r"
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+" => (7036, 45);
+    example2: r"
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+" => (11048, 64);
+}
@@ -1,4 +1,4 @@
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 struct Machine {
@@ -124,7 +124,7 @@ impl Solution for Day17 {
         self.data = operations.split(",").map(|x| x.parse::<u8>().unwrap()).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut machine = Machine {
             a: self.initial_values.0,
             b: self.initial_values.1,
@@ -145,12 +145,12 @@ impl Solution for Day17 {
                 building = format!("{}{}", building, o);
             }
         }
-        building
+        building.into()
     }
 
     #[allow(unreachable_code)]
-    fn part2(&self) -> String {
-        return "Not Implemented".to_string();
+    fn part2(&self) -> Answer {
+        return Answer::NotImplemented;
 
         // So, the program ends with jmp-ing back to 0
         assert!(self.data[self.data.len() - 2] == 3); // jnz
@@ -238,7 +238,17 @@ impl Solution for Day17 {
         dbg!(machine.outputs.len(), self.data.len());
         assert!(dbg!(machine.outputs) == *dbg!(&self.data));
 
-        total.to_string()
+        total.into()
     }
 }
 
+examples! {
+    Day17::new();
+    example: r"
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+" => ("4,6,3,5,6,3,5,2,1,0", _);
+}
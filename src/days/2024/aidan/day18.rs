@@ -1,34 +1,74 @@
 
 use std::{cmp::min, collections::{HashSet, VecDeque}};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
+use crate::visualize::{emit, Colour, Frame, Visualize};
 
 const WIDTH : usize = 71;
 const HEIGHT : usize = 71;
+const FALLEN : usize = 1024;
+
+// The memory space as the bytes fall, for drawing
+struct Memory<'a> {
+    grid: &'a Vec<Vec<bool>>,
+    latest: Option<(usize, usize)>,
+}
+
+impl Visualize for Memory<'_> {
+    fn draw(&self) -> Frame {
+        let (width, height) = (self.grid.first().map_or(0, |x| x.len()), self.grid.len());
+        let mut frame = Frame::new(width, height);
+        for (y, row) in self.grid.iter().enumerate() {
+            for (x, free) in row.iter().enumerate() {
+                match free {
+                    true => frame.set(x, y, '.', Colour::Dark),
+                    false => frame.set(x, y, '#', Colour::Grey),
+                }
+            }
+        }
+        if let Some((x, y)) = self.latest {
+            frame.set(x, y, '#', Colour::Red);
+        }
+        frame.set(0, 0, 'S', Colour::Green);
+        frame.set(width.saturating_sub(1), height.saturating_sub(1), 'E', Colour::Green);
+        frame
+    }
+}
 
 #[derive(Debug)]
 pub struct Day18 {
     // State generated by `parse_input`
-    falling: Vec<(usize, usize)>
+    falling: Vec<(usize, usize)>,
+    width: usize,
+    height: usize,
+    fallen: usize,
 }
 
 impl Day18 {
     // Needed for creating a blank day
     pub fn new() -> Day18 {
+        Day18::with_size(WIDTH, HEIGHT, FALLEN)
+    }
+
+    // The example uses a smaller memory space and fewer bytes for part 1
+    pub fn with_size(width: usize, height: usize, fallen: usize) -> Day18 {
         Day18 {
             falling: vec![],
+            width,
+            height,
+            fallen,
         }
     }
 
-    fn is_valid(grid: &Vec<Vec<bool>>, x: usize, y: usize) -> bool {
-        if x >= WIDTH || y >= HEIGHT {
+    fn is_valid(&self, grid: &Vec<Vec<bool>>, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height {
             false
         } else {
             grid[y][x]
         }
     }
 
-    fn bfs(grid: &Vec<Vec<bool>>, start: (usize, usize), end: (usize, usize)) -> Option<usize> {
+    fn bfs(&self, grid: &Vec<Vec<bool>>, start: (usize, usize), end: (usize, usize)) -> Option<usize> {
         let mut seen : HashSet::<(usize, usize)> = HashSet::new();
         let mut queue : VecDeque<(usize, usize, usize)> = VecDeque::new();
         queue.push_back((start.0, start.1, 0));
@@ -48,28 +88,28 @@ impl Day18 {
             {
                 let x = x + 1;
                 let y = y;
-                if Self::is_valid(grid, x, y) {
+                if self.is_valid(grid, x, y) {
                     queue.push_back((x, y, current.2 + 1));
                 }
             }
             {
                 let x = x.wrapping_sub(1);
                 let y = y;
-                if Self::is_valid(grid, x, y) {
+                if self.is_valid(grid, x, y) {
                     queue.push_back((x, y, current.2 + 1));
                 }
             }
             {
                 let x = x;
                 let y = y + 1;
-                if Self::is_valid(grid, x, y) {
+                if self.is_valid(grid, x, y) {
                     queue.push_back((x, y, current.2 + 1));
                 }
             }
             {
                 let x = x;
                 let y = y.wrapping_sub(1);
-                if Self::is_valid(grid, x, y) {
+                if self.is_valid(grid, x, y) {
                     queue.push_back((x, y, current.2 + 1));
                 }
             }
@@ -93,22 +133,23 @@ impl Solution for Day18 {
         }).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         // Apply the first 1024
-        let mut grid = vec![vec![true; WIDTH]; HEIGHT];
-        let apply = min(1024, self.falling.len());
-        for pos in &self.falling.as_slice()[0..apply] {
+        let mut grid = vec![vec![true; self.width]; self.height];
+        let apply = min(self.fallen, self.falling.len());
+        for (idx, pos) in self.falling.as_slice()[0..apply].iter().enumerate() {
             grid[pos.1][pos.0] = false;
+            emit(&Memory { grid: &grid, latest: Some(*pos) }, format_args!("{} bytes have fallen", idx + 1));
         }
 
-        Self::bfs(&grid, (0, 0), (WIDTH - 1, HEIGHT - 1)).unwrap().to_string()
+        self.bfs(&grid, (0, 0), (self.width - 1, self.height - 1)).unwrap().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Binary search
         let mut right = self.falling.len() - 1;
-        let mut left = 1024;
-        let mut grid = vec![vec![true; WIDTH]; HEIGHT];
+        let mut left = self.fallen;
+        let mut grid = vec![vec![true; self.width]; self.height];
 
         while right > left {
             let middle = (right + left) / 2;
@@ -116,7 +157,9 @@ impl Solution for Day18 {
             for pos in &self.falling.as_slice()[0..middle] {
                 grid[pos.1][pos.0] = false;
             }
-            let result = Self::bfs(&grid, (0, 0), (WIDTH - 1, HEIGHT - 1));
+            let result = self.bfs(&grid, (0, 0), (self.width - 1, self.height - 1));
+            let reachable = if result.is_some() { "the exit is reachable" } else { "the exit is cut off" };
+            emit(&Memory { grid: &grid, latest: Some(self.falling[middle - 1]) }, format_args!("After {middle} bytes {reachable}"));
             // Reset the grid
             for pos in &self.falling.as_slice()[0..middle] {
                 grid[pos.1][pos.0] = true;
@@ -132,6 +175,37 @@ impl Solution for Day18 {
         let pos = self.falling[right];
         let x = pos.0;
         let y = pos.1;
-        format!("{},{}", x, y)
+        format!("{},{}", x, y).into()
     }
 }
+
+examples! {
+    Day18::with_size(7, 7, 12);
+    example: r"
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+" => (22, "6,1");
+}
@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day19 {
@@ -45,7 +45,7 @@ impl Solution for Day19 {
         self.goals = itr.next().unwrap().split("\n").map(|x| x.to_string()).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut total = 0;
         for goal in &self.goals {
             let mut finishing_places_by_start_idx = vec![];
@@ -68,10 +68,10 @@ impl Solution for Day19 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut total = 0;
         for goal in &self.goals {
             let mut finishing_places_by_start_idx = vec![];
@@ -110,6 +110,22 @@ impl Solution for Day19 {
             total += paths_to_end_by_idx[0];
         }
 
-        total.to_string()
+        total.into()
     }
 }
+
+examples! {
+    Day19::new();
+    example: r"
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+" => (6, 16);
+}
@@ -40,7 +40,9 @@ b. Repeat the process for every tile within a distance of 20 from "source" for p
 
 use std::collections::{HashMap, VecDeque};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
+
+const MINIMUM_SAVING: u64 = 100;
 
 #[derive(Debug)]
 pub struct Day20 {
@@ -48,25 +50,28 @@ pub struct Day20 {
     passable: Vec<Vec<bool>>,
     start_pos: (usize, usize),
     end_pos: (usize, usize),
+    minimum_saving: u64,
+    // Generated by `prepare`
+    distance_from_start: Vec<Vec<Option<u64>>>,
+    distance_from_end: Vec<Vec<Option<u64>>>,
 }
 
 impl Day20 {
     // Needed for creating a blank day
     pub fn new() -> Day20 {
-        Day20 { passable: vec![], start_pos: (0, 0), end_pos: (0, 0) }
+        Day20::with_minimum_saving(MINIMUM_SAVING)
     }
 
-    fn paths(&self, skippable_steps: u64) -> HashMap<u64, u64> {
-        // Compute the original shortest path length using BFS
-        let original_length = {
-            let distances = compute_distances(self.start_pos, &self.passable);
-            distances[self.end_pos.0][self.end_pos.1].unwrap()
-        };
+    // The example maze is too small to save 100 picoseconds
+    pub fn with_minimum_saving(minimum_saving: u64) -> Day20 {
+        Day20 { passable: vec![], start_pos: (0, 0), end_pos: (0, 0), minimum_saving, distance_from_start: vec![], distance_from_end: vec![] }
+    }
 
-        // Compute distance from start for all passable tiles
-        let distance_from_start = compute_distances(self.start_pos, &self.passable);
-        // Compute distance to end for all passable tiles (using BFS from end)
-        let distance_from_end = compute_distances(self.end_pos, &self.passable);
+    fn paths(&self, skippable_steps: u64) -> HashMap<u64, u64> {
+        let distance_from_start = &self.distance_from_start;
+        let distance_from_end = &self.distance_from_end;
+        // The original shortest path length
+        let original_length = distance_from_start[self.end_pos.0][self.end_pos.1].unwrap();
 
         let mut result = HashMap::new();
         let rows = self.passable.len();
@@ -183,6 +188,8 @@ impl Solution for Day20 {
         self.passable = vec![];
         self.start_pos = (0, 0);
         self.end_pos = (0, 0);
+        self.distance_from_start = vec![];
+        self.distance_from_end = vec![];
     }
 
     fn parse_input(&mut self, file_contents: &str) {
@@ -203,11 +210,39 @@ impl Solution for Day20 {
         }).collect();
     }
 
-    fn part1(&self) -> String {
-        self.paths(2).iter().filter(|(key, _)| **key >= 100).fold(0, |acc, (_, x)| acc + x).to_string()
+    fn prepare(&mut self) {
+        // Compute distance from start for all passable tiles
+        self.distance_from_start = compute_distances(self.start_pos, &self.passable);
+        // Compute distance to end for all passable tiles (using BFS from end)
+        self.distance_from_end = compute_distances(self.end_pos, &self.passable);
+    }
+
+    fn part1(&self) -> Answer {
+        self.paths(2).iter().filter(|(key, _)| **key >= self.minimum_saving).fold(0, |acc, (_, x)| acc + x).into()
     }
 
-    fn part2(&self) -> String {
-        self.paths(20).iter().filter(|(key, _)| **key >= 100).fold(0, |acc, (_, x)| acc + x).to_string()
+    fn part2(&self) -> Answer {
+        self.paths(20).iter().filter(|(key, _)| **key >= self.minimum_saving).fold(0, |acc, (_, x)| acc + x).into()
     }
 }
+
+examples! {
+    Day20::with_minimum_saving(50);
+    example: r"
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+" => (1, 285);
+}
@@ -1,6 +1,6 @@
 use std::mem::swap;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DirectionalKeypad {
@@ -419,11 +419,22 @@ impl Solution for Day21 {
         self.paths = file_contents.split("\n").map(|x| x.to_string()).collect();
     }
 
-    fn part1(&self) -> String {
-        self.solve(3).to_string()
+    fn part1(&self) -> Answer {
+        self.solve(3).into()
     }
 
-    fn part2(&self) -> String {
-        self.solve(26).to_string()
+    fn part2(&self) -> Answer {
+        self.solve(26).into()
     }
 }
+
+examples! {
+    Day21::new();
+    example: r"
+029A
+980A
+179A
+456A
+379A
+" => (126384, _);
+}
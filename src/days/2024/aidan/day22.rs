@@ -1,7 +1,7 @@
 
 use std::collections::HashMap;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 const MOD: u64 = 16777216;
 
@@ -36,17 +36,17 @@ impl Solution for Day22 {
         self.initial_numbers = file_contents.split("\n").map(|x| x.parse().unwrap()).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.initial_numbers.iter().map(|x| {
             let mut number = *x;
             for _ in 0..2000 {
                 number = Self::next(number);
             }
             number
-        }).sum::<i64>().to_string()
+        }).sum::<i64>().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut values = HashMap::new();
 
         for initial in &self.initial_numbers {
@@ -102,6 +102,22 @@ impl Solution for Day22 {
         }
 
         // Find the highest value
-        values.iter().max_by(|x, y| x.1.cmp(y.1)).unwrap().1.to_string()
+        (*values.iter().max_by(|x, y| x.1.cmp(y.1)).unwrap().1).into()
     }
 }
+
+examples! {
+    Day22::new();
+    example1: r"
+1
+10
+100
+2024
+" => (37327623, _);
+    example2: r"
+1
+2
+3
+2024
+" => (_, 23);
+}
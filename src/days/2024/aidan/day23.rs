@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::collections::{HashMap, HashSet};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 struct NetworkThree {
@@ -117,17 +117,15 @@ impl Solution for Day23 {
         }
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut threes: HashSet<NetworkThree> = HashSet::new();
 
         // Take every 2-pair and count the number that they have in common, removing from the list
-        let mut starts_with_t;
         let mut itr1 = self.outgoing.iter();
         while let Some(left) = itr1.next() {
-            starts_with_t = left.0.chars().next().unwrap() == 'c';
             let mut itr2 = itr1.clone();
             while let Some(right) = itr2.next() {
-                starts_with_t = starts_with_t || right.0.chars().next().unwrap() == 'c';
+                let starts_with_t = left.0.starts_with('t') || right.0.starts_with('t');
                 // Only count if these two are connected
                 if left.1.contains(right.0) {
                     assert!(right.1.contains(left.0));
@@ -135,7 +133,7 @@ impl Solution for Day23 {
                     let shared = left.1.intersection(right.1);
                     for third in shared {
                         // We only want connections that contain a 't'
-                        if starts_with_t || third.chars().next().unwrap() == 'c' {
+                        if starts_with_t || third.starts_with('t') {
                             threes.insert(NetworkThree::from(left.0, right.0, third));
                         }
                     }
@@ -159,10 +157,10 @@ impl Solution for Day23 {
             }
         }
 
-        threes.iter().count().to_string()
+        threes.iter().count().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // The largest connected component can be determined by looking at the number of edges
         // a node has.
         let mut outgoing: Vec<(&String, &HashSet<String>)> = self.outgoing.iter().collect();
@@ -172,6 +170,44 @@ impl Solution for Day23 {
         let p = self.outgoing.keys().cloned().collect::<HashSet<_>>();
         let x = HashSet::new();
 
-        self.find_largest_clique(r, p, x).unwrap().to_string()
+        self.find_largest_clique(r, p, x).unwrap().into()
     }
 }
+
+examples! {
+    Day23::new();
+    example: r"
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+" => (7, "co,de,ka,ta");
+}
@@ -1,7 +1,7 @@
 
 use std::collections::HashMap;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug, Clone)]
 enum Op {
@@ -90,7 +90,7 @@ impl Solution for Day24 {
         self.gates = itr.next().unwrap().split("\n").map(|x| Gate::from(x)).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut state = self.initial_state.clone();
         let mut unsolved_gates = self.gates.clone();
 
@@ -127,11 +127,27 @@ impl Solution for Day24 {
                 total += 2_usize.pow(magnitude);
             }
         }
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         dbg!(&self.gates.len());
-        "Not Implemented".to_string()
+        Answer::NotImplemented
     }
 }
+
+examples! {
+    Day24::new();
+    example: r"
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+" => (4, _);
+}
@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day25 {
@@ -77,15 +77,60 @@ impl Solution for Day25 {
         assert!(before_len == self.locks.len());
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.keys.iter().map(|key| {
             self.locks.iter().filter(|lock| {
                 key.iter().zip(lock.iter()).all(|(k, l)| k + l <= 5)
             }).count()
-        }).sum::<usize>().to_string()
+        }).sum::<usize>().into()
     }
 
-    fn part2(&self) -> String {
-        "No Part 2".to_owned()
+    fn part2(&self) -> Answer {
+        Answer::NotApplicable
     }
 }
+
+examples! {
+    Day25::new();
+    example: r"
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+" => (3, _);
+}
@@ -1,7 +1,7 @@
 
 use regex::Regex;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day3 {
@@ -41,11 +41,11 @@ impl Solution for Day3 {
         self.input = file_contents.to_string();
     }
 
-    fn part1(&self) -> String {
-        self.search(&self.input).to_string()
+    fn part1(&self) -> Answer {
+        self.search(&self.input).into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut slice = &self.input[..];
         let mut total = 0;
         loop {
@@ -66,6 +66,12 @@ impl Solution for Day3 {
 
             slice = &slice[start+end..];
         }
-        total.to_string()
+        total.into()
     }
 }
+
+examples! {
+    Day3::new();
+    example1: r"xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))" => (161, _);
+    example2: r"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))" => (_, 48);
+}
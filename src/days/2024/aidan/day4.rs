@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 const SEARCHING: [char; 4] = ['X', 'M', 'A', 'S'];
 const SEARCHING_BACKWARDS: [char; 4] = ['S', 'A', 'M', 'X'];
@@ -61,7 +61,7 @@ impl Solution for Day4 {
         }).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let width = self.grid[0].len();
         let height = self.grid.len();
 
@@ -98,10 +98,10 @@ impl Solution for Day4 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let width = self.grid[0].len();
         let height = self.grid.len();
 
@@ -135,6 +135,22 @@ impl Solution for Day4 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
 }
+
+examples! {
+    Day4::new();
+    example: r"
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+" => (18, 9);
+}
@@ -1,7 +1,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 type Page = usize;
 
@@ -67,7 +67,7 @@ impl Solution for Day5 {
         self.updates = updates.split("\n").map(|x| x.split(",").map(|x| x.parse::<Page>().unwrap()).collect::<Vec<Page>>()).collect();
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let total = self.updates.iter().map(|x| {
             if self.try_update(&x).is_none() {
                 // The middle value
@@ -77,10 +77,10 @@ impl Solution for Day5 {
             }
         }).sum::<usize>();
         
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Find the incorrectly ordered ones
         let incorrectly_ordered = self.updates.iter().filter_map(|x| {
             match self.try_update(&x) {
@@ -118,6 +118,40 @@ impl Solution for Day5 {
             total += pages[pages.len() / 2] as usize
         }
 
-        total.to_string()
+        total.into()
     }
 }
+
+examples! {
+    Day5::new();
+    example: r"
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+" => (143, 123);
+}
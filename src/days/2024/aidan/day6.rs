@@ -1,7 +1,8 @@
 
 use std::collections::HashSet;
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
+use crate::visualize::{drawing, emit, Colour, Frame, Visualize};
 
 use super::shared::{Direction, Position, Grid};
 
@@ -11,6 +12,8 @@ pub struct Day6 {
     // State generated by `parse_input`
     map: Grid<Empty>,
     start: Position,
+    // Every position on the guard's path, generated by `prepare`
+    path: HashSet<Position>,
 }
 
 impl Day6 {
@@ -19,6 +22,7 @@ impl Day6 {
         Day6 {  
             map: Grid::from(vec![]),
             start: (0, 0),
+            path: HashSet::new(),
         }
     }
 
@@ -50,10 +54,48 @@ impl Day6 {
     }
 }
 
+// The lab with the guard's path
+impl Visualize for Day6 {
+    fn draw(&self) -> Frame {
+        let (width, height) = (self.map.width(), self.map.height());
+        let mut frame = Frame::new(width as usize, height as usize);
+        for y in 0..height {
+            for x in 0..width {
+                match (self.map.get(&(x, y)), self.path.contains(&(x, y))) {
+                    (Some(false), _) => frame.set(x as usize, y as usize, '#', Colour::Grey),
+                    (_, true) => frame.set(x as usize, y as usize, 'X', Colour::Cyan),
+                    _ => frame.set(x as usize, y as usize, '.', Colour::Dark),
+                }
+            }
+        }
+        frame.set(self.start.0 as usize, self.start.1 as usize, '^', Colour::Red);
+        frame
+    }
+}
+
+// The obstructions tried so far, for drawing
+struct Obstructions<'a> {
+    day: &'a Day6,
+    looping: &'a [Position],
+    latest: Position,
+}
+
+impl Visualize for Obstructions<'_> {
+    fn draw(&self) -> Frame {
+        let mut frame = self.day.draw();
+        for block in self.looping {
+            frame.set(block.0 as usize, block.1 as usize, 'O', Colour::Yellow);
+        }
+        frame.set(self.latest.0 as usize, self.latest.1 as usize, 'O', Colour::Red);
+        frame
+    }
+}
+
 impl Solution for Day6 {
     fn reset(&mut self) {
         // Should probably do the same thing new() does.
         self.map = Grid::from(vec![]);
+        self.path.clear();
     }
 
     fn parse_input(&mut self, file_contents: &str) {
@@ -74,23 +116,22 @@ impl Solution for Day6 {
         self.map = Grid::from(map);
     }
 
-    fn part1(&self) -> String {
-        Self::walk(&self.map, self.start, Direction::Up)
+    fn prepare(&mut self) {
+        self.path = Self::walk(&self.map, self.start, Direction::Up)
             .unwrap()
             .into_iter()
             .map(|(pos, _)| pos)
-            .collect::<HashSet<Position>>()
-            .len()
-            .to_string()
+            .collect();
     }
 
-    fn part2(&self) -> String {
-        // Get all the positions on the original path
-        let mut block_positions = Self::walk(&self.map, self.start, Direction::Up)
-            .unwrap()
-            .into_iter()
-            .map(|(pos, _)| pos)
-            .collect::<HashSet<Position>>();
+    fn part1(&self) -> Answer {
+        emit(self, "The guard's path");
+        self.path.len().into()
+    }
+
+    fn part2(&self) -> Answer {
+        // Only positions on the original path can change it
+        let mut block_positions = self.path.clone();
         // Can't block the start
         block_positions.remove(&self.start);
         let block_positions = block_positions;
@@ -98,13 +139,38 @@ impl Solution for Day6 {
         // Create a copy of the grid to reuse
         let mut alternate = self.map.clone();
 
+        // The obstructions that make the guard loop, only kept to draw them
+        let mut looping = vec![];
+
         // Put a block at every position, then total the number that don't make it.
         block_positions.into_iter().map(|block| {
             assert!(alternate.get(&block) == Some(&true));
             alternate.set(&block, false).unwrap();
             let res = Self::walk(&alternate, self.start, Direction::Up);
             alternate.set(&block, true).unwrap();
+            if drawing() {
+                if res.is_none() {
+                    looping.push(block);
+                }
+                emit(&Obstructions { day: self, looping: &looping, latest: block }, format_args!("{} obstructions make the guard loop", looping.len()));
+            }
             res.is_none() as u64
-        }).sum::<u64>().to_string()
+        }).sum::<u64>().into()
     }
 }
+
+examples! {
+    Day6::new();
+    example: r"
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+" => (41, 6);
+}
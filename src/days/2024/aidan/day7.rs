@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day7 {
@@ -29,7 +29,7 @@ impl Solution for Day7 {
         }).collect()
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         self.equations.iter().filter_map(|x| {
             let mut possible = vec![];
             let mut itr = x.1.iter();
@@ -47,10 +47,10 @@ impl Solution for Day7 {
             } else {
                 None
             }
-        }).sum::<i64>().to_string()
+        }).sum::<i64>().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         self.equations.iter().filter_map(|x| {
             let mut possible = vec![];
             let mut itr = x.1.iter();
@@ -71,6 +71,21 @@ impl Solution for Day7 {
             } else {
                 None
             }
-        }).sum::<i64>().to_string()
+        }).sum::<i64>().into()
     }
 }
+
+examples! {
+    Day7::new();
+    example: r"
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+" => (3749, 11387);
+}
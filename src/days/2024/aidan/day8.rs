@@ -1,7 +1,7 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day8 {
@@ -46,7 +46,7 @@ impl Solution for Day8 {
         // dbg!(self.width, self.height, &self.nodes);
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut antinodes = HashSet::new();
 
         for (_, positions) in &self.nodes {
@@ -77,10 +77,10 @@ impl Solution for Day8 {
             }
         }
 
-        antinodes.len().to_string()
+        antinodes.len().into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         let mut antinodes = HashSet::new();
 
         for (_, positions) in &self.nodes {
@@ -120,6 +120,24 @@ impl Solution for Day8 {
             }
         }
 
-        antinodes.len().to_string()
+        antinodes.len().into()
     }
 }
+
+examples! {
+    Day8::new();
+    example: r"
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+" => (14, 34);
+}
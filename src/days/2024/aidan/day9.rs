@@ -1,4 +1,4 @@
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct Day9 {
@@ -39,7 +39,7 @@ impl Solution for Day9 {
         }
     }
 
-    fn part1(&self) -> String {
+    fn part1(&self) -> Answer {
         let mut total = 0;
 
         let mut left_idx = 0;
@@ -89,10 +89,10 @@ impl Solution for Day9 {
 
         assert!(current_block_idx == self.block_counts.iter().sum());
 
-        total.to_string()
+        total.into()
     }
 
-    fn part2(&self) -> String {
+    fn part2(&self) -> Answer {
         // Brute forcing :(
 
         // Figure out the first index of all the free spaces
@@ -152,6 +152,11 @@ impl Solution for Day9 {
             }
         }
 
-        total.to_string()
+        total.into()
     }
-}
\ No newline at end of file
+}
+
+examples! {
+    Day9::new();
+    example: r"2333133121414131402" => (1928, 2858);
+}
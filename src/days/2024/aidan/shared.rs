@@ -42,6 +42,14 @@ impl<T> Grid<T> {
         Grid{grid, width}
     }
 
+    pub fn width(&self) -> PositionT {
+        self.width
+    }
+
+    pub fn height(&self) -> PositionT {
+        self.grid.len() as PositionT
+    }
+
     pub fn get(&self, position: &Position) -> Option<&T> {
         let (x, y) = *position;
         if x >= self.width || y >= self.grid.len() as PositionT {
@@ -1,5 +1,5 @@
 
-use crate::days::Solution;
+use crate::days::{Answer, Solution};
 
 #[derive(Debug)]
 pub struct DayNUMBER {
@@ -9,25 +9,32 @@ pub struct DayNUMBER {
 impl DayNUMBER {
     // Needed for creating a blank day
     pub fn new() -> DayNUMBER {
-        todo!()
+        DayNUMBER {}
     }
 }
 
 impl Solution for DayNUMBER {
     fn reset(&mut self) {
         // Should probably do the same thing new() does.
-        todo!()
+        *self = DayNUMBER::new();
     }
 
-    fn parse_input(&mut self, file_contents: &str) {
-        todo!()
+    fn parse_input(&mut self, _file_contents: &str) {
+        // Fill in the state from the input
     }
 
-    fn part1(&self) -> String {
-        "Not Implemented".to_string()
+    fn part1(&self) -> Answer {
+        Answer::NotImplemented
     }
 
-    fn part2(&self) -> String {
-        "Not Implemented".to_string()
+    fn part2(&self) -> Answer {
+        Answer::NotImplemented
     }
 }
+
+examples! {
+    DayNUMBER::new();
+    example: r"
+PASTE THE EXAMPLE HERE
+" => (_, _);
+}
@@ -1 +0,0 @@
-// ADD_MOD_HERE
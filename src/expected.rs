@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use crate::crash::catch_panic;
+use crate::days::{get_answer, get_solutions, runs_part, Answer, Person};
+use crate::input::{resolve_input, DEFAULT_VARIANT};
+
+pub const EXPECTED_ANSWERS_FILE_PATH: &str = "answers.tsv";
+
+const HEADER: &str = "# year\tperson\tday\tpart\tvariant\tanswer";
+
+// (year, person, day, part, variant). The variant rather than its file, so answers don't depend on where the input lives.
+type Key = (usize, String, usize, usize, String);
+
+// Known-correct answers, checked in so refactors can be verified against them
+pub struct ExpectedAnswers {
+    answers: BTreeMap<Key, Answer>,
+}
+
+impl ExpectedAnswers {
+    pub fn load(filepath: &str) -> Result<ExpectedAnswers, String> {
+        let mut answers = BTreeMap::new();
+        if !Path::new(filepath).exists() {
+            return Ok(ExpectedAnswers { answers });
+        }
+
+        let contents = fs::read_to_string(filepath).map_err(|e| format!("Failed to read {filepath}: {e}"))?;
+        for (idx, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let bad_line = || format!("{filepath}:{}: expected 6 tab-separated fields, got '{line}'", idx + 1);
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 6 {
+                return Err(bad_line());
+            }
+            let year = fields[0].parse::<usize>().map_err(|_| bad_line())?;
+            let day = fields[2].parse::<usize>().map_err(|_| bad_line())?;
+            let part = fields[3].parse::<usize>().map_err(|_| bad_line())?;
+            let key = (year, fields[1].to_owned(), day, part, fields[4].to_owned());
+            answers.insert(key, Answer::parse(fields[5]));
+        }
+        Ok(ExpectedAnswers { answers })
+    }
+
+    pub fn save(&self, filepath: &str) -> std::io::Result<()> {
+        let mut contents = String::from(HEADER);
+        contents.push('\n');
+        for ((year, person, day, part, variant), answer) in &self.answers {
+            contents.push_str(&format!("{year}\t{person}\t{day}\t{part}\t{variant}\t{answer}\n"));
+        }
+        fs::write(filepath, contents)
+    }
+
+    pub fn get(&self, year: usize, person: &Person, day: usize, part: usize, variant: &str) -> Option<&Answer> {
+        self.answers.get(&(year, person.name().to_owned(), day, part, variant.to_owned()))
+    }
+
+    // How many answers are expected from everything `person` solved in `year` for `variant`
+    pub fn count(&self, year: usize, person: &Person, variant: &str) -> usize {
+        self.answers.keys().filter(|x| x.0 == year && x.1 == person.name() && x.4 == variant).count()
+    }
+
+    // Only answers that earned a star are worth remembering
+    pub fn set(&mut self, year: usize, person: &Person, day: usize, part: usize, variant: &str, answer: Answer) {
+        let key = (year, person.name().to_owned(), day, part, variant.to_owned());
+        if answer.is_complete() {
+            self.answers.insert(key, answer);
+        } else {
+            self.answers.remove(&key);
+        }
+    }
+}
+
+// Re-run every registered solution and compare it against the expected answers.
+// Returns false if any answer regressed.
+pub fn verify_all(year: usize, by: Person, variant: Option<&str>, part: Option<usize>, expected: &ExpectedAnswers) -> bool {
+    let variant = variant.unwrap_or(DEFAULT_VARIANT);
+    let mut all_days = get_solutions(year, by.clone()).into_iter().collect::<Vec<_>>();
+    all_days.sort_by_key(|x| x.0);
+
+    let mut checked = 0;
+    let mut mismatches = 0;
+    let mut crashes = 0;
+
+    for (day_number, sol) in all_days.iter_mut() {
+        let wanted = [1, 2].map(|part| expected.get(year, &by, *day_number, part, variant));
+        if wanted.iter().all(|x| x.is_none()) {
+            println!("Day {day_number:2}: no expected answers for the '{variant}' input");
+            continue;
+        }
+        let input = match resolve_input(year, &by, *day_number, Some(variant)) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {day_number:2}: skipped, {e}");
+                continue;
+            }
+        };
+        if !input.exists() {
+            println!("Day {day_number:2}: skipped, missing {}", input.path);
+            continue;
+        }
+
+        // A day that panics is a failure, but the days after it still get checked
+        let (part1, part2) = match catch_panic(|| get_answer(sol, &input, part)) {
+            Ok(answers) => answers,
+            Err(crash) => {
+                // Every answer it should have given counts as wrong
+                let lost = (1..=2).filter(|x| runs_part(part, *x) && wanted[x - 1].is_some()).count();
+                checked += lost;
+                mismatches += lost;
+                crashes += 1;
+                println!("Day {day_number:2}: CRASHED {crash}");
+                continue;
+            }
+        };
+        for (idx, (wanted, got)) in wanted.iter().zip([part1, part2]).enumerate() {
+            let Some(wanted) = wanted else {
+                continue;
+            };
+            if got == Answer::Skipped {
+                println!("Day {day_number:2} part {}: skipped", idx + 1);
+                continue;
+            }
+            checked += 1;
+            if **wanted == got {
+                println!("Day {day_number:2} part {}: ok ({got})", idx + 1);
+            } else {
+                mismatches += 1;
+                println!("Day {day_number:2} part {}: MISMATCH expected {wanted}, got {got}", idx + 1);
+            }
+        }
+    }
+
+    println!("Verified [{}/{}]", checked - mismatches, checked);
+    if crashes > 0 {
+        println!("FAILED: {crashes} {} crashed", if crashes == 1 { "day" } else { "days" });
+    }
+    // Answers that are all skipped usually means the inputs moved, which shouldn't look like a pass
+    let recorded = expected.count(year, &by, variant);
+    if checked == 0 && recorded > 0 {
+        println!("FAILED: none of the {recorded} expected answers for the '{variant}' input were checked. Are the inputs missing?");
+        return false;
+    }
+    mismatches == 0 && crashes == 0
+}
+
+// Store the current answers of every registered solution as the new expected answers
+pub fn record_all(year: usize, by: Person, variant: Option<&str>, part: Option<usize>, expected: &mut ExpectedAnswers) {
+    let variant = variant.unwrap_or(DEFAULT_VARIANT);
+    let mut all_days = get_solutions(year, by.clone()).into_iter().collect::<Vec<_>>();
+    all_days.sort_by_key(|x| x.0);
+
+    for (day_number, sol) in all_days.iter_mut() {
+        let input = match resolve_input(year, &by, *day_number, Some(variant)) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {day_number:2}: skipped, {e}");
+                continue;
+            }
+        };
+        if !input.exists() {
+            println!("Day {day_number:2}: skipped, missing {}", input.path);
+            continue;
+        }
+
+        // Keep whatever was recorded for a day that crashed
+        let (part1, part2) = match catch_panic(|| get_answer(sol, &input, part)) {
+            Ok(answers) => answers,
+            Err(crash) => {
+                println!("Day {day_number:2}: skipped, crashed {crash}");
+                continue;
+            }
+        };
+        println!("Day {day_number:2}: recorded {part1}, {part2}");
+        for (number, answer) in [(1, part1), (2, part2)] {
+            // Keep whatever was recorded for a part that didn't run
+            if answer != Answer::Skipped {
+                expected.set(year, &by, *day_number, number, variant, answer);
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{fs, io::Write, path::Path, process::Command};
+
+use crate::baseline::{summaries, BASELINE_DIRECTORY};
+use crate::days::{get_formatted_time, Person};
+use crate::output::DayResult;
+
+const HEADER: &str = "# timestamp\tcommit\tyear\tperson\tday\tphase\tsamples\tmean_ns\tmedian_ns";
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+fn history_path() -> String {
+    format!("{BASELINE_DIRECTORY}/history.tsv")
+}
+
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
+pub enum Phase {
+    Parsing,
+    Prepare,
+    Part1,
+    Part2,
+    #[default]
+    Total,
+}
+
+impl Phase {
+    // The same names as in baselines
+    fn name(&self) -> &'static str {
+        match self {
+            Phase::Parsing => "Parsing",
+            Phase::Prepare => "Prepare",
+            Phase::Part1 => "Part 1",
+            Phase::Part2 => "Part 2",
+            Phase::Total => "Total",
+        }
+    }
+}
+
+// One benchmarked phase of one day, from one `bench` run
+pub struct Entry {
+    pub timestamp: String,
+    pub commit: String,
+    pub year: usize,
+    pub person: String,
+    pub day: usize,
+    pub phase: String,
+    pub samples: usize,
+    pub mean: f64,
+    pub median: f64,
+}
+
+// The commit being benchmarked, marked dirty when there are uncommitted changes since the numbers aren't from it alone
+fn current_commit() -> String {
+    let git = |args: &[&str]| Command::new("git").args(args).output().ok().filter(|x| x.status.success());
+    let Some(head) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_owned();
+    };
+    let commit = String::from_utf8_lossy(&head.stdout).trim().to_owned();
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.stdout.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
+
+// Add every benchmarked phase of `results` run on the real input to the history. Returns how many were added.
+pub fn append_history(results: &[DayResult]) -> Result<usize, String> {
+    let filepath = history_path();
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let commit = current_commit();
+
+    let mut rows = String::new();
+    let mut added = 0;
+    for result in results {
+        for (phase, summary) in summaries(result) {
+            rows.push_str(&format!(
+                "{timestamp}\t{commit}\t{}\t{}\t{}\t{phase}\t{}\t{}\t{}\n",
+                result.year, result.person.name(), result.day, summary.samples, summary.mean, summary.median
+            ));
+            added += 1;
+        }
+    }
+    if added == 0 {
+        return Ok(0);
+    }
+
+    let write = || -> std::io::Result<()> {
+        fs::create_dir_all(BASELINE_DIRECTORY)?;
+        let is_new = !Path::new(&filepath).exists();
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&filepath)?;
+        if is_new {
+            writeln!(file, "{HEADER}")?;
+        }
+        file.write_all(rows.as_bytes())
+    };
+    write().map_err(|e| format!("Failed to add to the history in {filepath}: {e}"))?;
+    Ok(added)
+}
+
+pub fn load_history() -> Result<Vec<Entry>, String> {
+    let filepath = history_path();
+    if !Path::new(&filepath).exists() {
+        return Ok(vec![]);
+    }
+    let contents = fs::read_to_string(&filepath).map_err(|e| format!("Failed to read {filepath}: {e}"))?;
+    let mut entries = vec![];
+    for (idx, line) in contents.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let bad_line = || format!("{filepath}:{}: malformed history entry '{line}'", idx + 1);
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() != 9 {
+            return Err(bad_line());
+        }
+        entries.push(Entry {
+            timestamp: fields[0].to_owned(),
+            commit: fields[1].to_owned(),
+            year: fields[2].parse().map_err(|_| bad_line())?,
+            person: fields[3].to_owned(),
+            day: fields[4].parse().map_err(|_| bad_line())?,
+            phase: fields[5].to_owned(),
+            samples: fields[6].parse().map_err(|_| bad_line())?,
+            mean: fields[7].parse().map_err(|_| bad_line())?,
+            median: fields[8].parse().map_err(|_| bad_line())?,
+        });
+    }
+    Ok(entries)
+}
+
+// One bar per value, from the fastest to the slowest
+pub fn sparkline(values: &[f64]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    values.iter().map(|value| {
+        if max <= min {
+            return SPARKS[0];
+        }
+        let level = ((value - min) / (max - min) * (SPARKS.len() - 1) as f64).round() as usize;
+        SPARKS[level]
+    }).collect()
+}
+
+fn format_nanos(nanos: f64) -> String {
+    get_formatted_time(&std::time::Duration::from_nanos(nanos as u64))
+}
+
+// Prints how a day's phase changed over the last `last` runs, oldest first
+pub fn print_history(entries: &[Entry], year: usize, by: &Person, day_number: usize, phase: Phase, last: usize) {
+    let runs = entries.iter()
+        .filter(|x| x.year == year && x.person == by.name() && x.day == day_number && x.phase == phase.name())
+        .collect::<Vec<&Entry>>();
+    let runs = &runs[runs.len().saturating_sub(last)..];
+    let (Some(first), Some(latest)) = (runs.first(), runs.last()) else {
+        println!("No history for {} day {day_number} of {year} yet. Every `bench` run adds to it.", by.name());
+        return;
+    };
+
+    let count = if runs.len() == 1 { "1 run".to_owned() } else { format!("{} runs", runs.len()) };
+    println!("{} of {} day {day_number} of {year} over {count}:", phase.name(), by.name());
+    println!("{}  {} -> {} ({:+.1}%)", sparkline(&runs.iter().map(|x| x.mean).collect::<Vec<f64>>()),
+        format_nanos(first.mean), format_nanos(latest.mean), (latest.mean / first.mean - 1.) * 100.);
+    println!("| {:<19} {:<14} {:>8} {:>8} {:>8} {:>8}", "when", "commit", "samples", "mean", "median", "change");
+    let mut previous: Option<f64> = None;
+    for run in runs {
+        let change = previous.map_or(String::new(), |x| format!("{:+.1}%", (run.mean / x - 1.) * 100.));
+        println!("| {:<19} {:<14} {:>8} {:>8} {:>8} {:>8}", run.timestamp, run.commit, run.samples, format_nanos(run.mean), format_nanos(run.median), change);
+        previous = Some(run.mean);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparkline_scales_between_the_fastest_and_slowest() {
+        assert_eq!(sparkline(&[100., 800., 450., 100.]), "▁█▅▁");
+        assert_eq!(sparkline(&[5., 5.]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+}
@@ -0,0 +1,200 @@
+use std::{fmt, io::Read, path::Path, sync::OnceLock};
+
+use crate::days::{normalize_input, read_file, Person};
+
+// The puzzle input everyone gets, as opposed to examples
+pub const DEFAULT_VARIANT: &str = "real";
+
+// Why an input file was picked
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    // Given with `--input`
+    Explicit,
+    // Piped in with `--input -`
+    Stdin,
+    // Given with `--input-text`
+    Inline,
+    // `data/<year>/<person>/dayN/<variant>.txt`
+    Variant(String),
+    // `data/<year>/dayN.txt`, because the person has no file of their own
+    Shared { missing: String },
+    // `data/dayN.txt`, where 2024's inputs were kept before there were years
+    Legacy { missing: [String; 2] },
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Explicit => write!(f, "given with --input"),
+            InputSource::Stdin => write!(f, "read from stdin"),
+            InputSource::Inline => write!(f, "given with --input-text"),
+            InputSource::Variant(variant) => write!(f, "'{variant}' variant"),
+            InputSource::Shared { missing } => write!(f, "shared input, there is no {missing}"),
+            InputSource::Legacy { missing } => write!(f, "shared input from before years, there is no {} or {}", missing[0], missing[1]),
+        }
+    }
+}
+
+impl InputSource {
+    // Whether this is the real puzzle input, rather than an example or a one-off file
+    pub fn is_puzzle_input(&self) -> bool {
+        match self {
+            InputSource::Variant(variant) => variant == DEFAULT_VARIANT,
+            InputSource::Shared { .. } | InputSource::Legacy { .. } => true,
+            InputSource::Explicit | InputSource::Stdin | InputSource::Inline => false,
+        }
+    }
+}
+
+pub struct Input {
+    // What to call the input when it didn't come from a file
+    pub path: String,
+    pub source: InputSource,
+    // Set when the input didn't come from a file, since stdin can only be read once
+    contents: Option<String>,
+}
+
+impl Input {
+    pub fn explicit(path: &str) -> Input {
+        Input { path: path.to_owned(), source: InputSource::Explicit, contents: None }
+    }
+
+    // `-` reads stdin, like most command line tools
+    pub fn from_arg(path: &Path) -> Result<Input, String> {
+        if path != Path::new("-") {
+            let input = Input::explicit(path.to_str().unwrap());
+            if !input.exists() {
+                return Err(format!("Input file {} doesn't exist", input.path));
+            }
+            return Ok(input);
+        }
+        let mut contents = String::new();
+        std::io::stdin().read_to_string(&mut contents).map_err(|e| format!("Failed to read the input from stdin: {e}"))?;
+        Ok(Input { path: "<stdin>".to_owned(), source: InputSource::Stdin, contents: Some(contents) })
+    }
+
+    pub fn inline(text: &str) -> Input {
+        Input { path: "<inline>".to_owned(), source: InputSource::Inline, contents: Some(text.to_owned()) }
+    }
+
+    pub fn exists(&self) -> bool {
+        self.contents.is_some() || Path::new(&self.path).exists()
+    }
+
+    // Whether the input lives in a file that can change
+    pub fn is_file(&self) -> bool {
+        self.contents.is_none()
+    }
+
+    pub fn read(&self) -> String {
+        match &self.contents {
+            Some(contents) => normalize_input(contents),
+            None => read_file(&self.path),
+        }
+    }
+}
+
+pub const DEFAULT_INPUT_ROOT: &str = "data";
+
+// The only year whose inputs were kept straight in `data/`
+const LEGACY_INPUT_YEAR: usize = 2024;
+
+static INPUT_ROOT: OnceLock<String> = OnceLock::new();
+
+// Keep every input under `root` instead of `data`, for the rest of the run
+pub fn set_input_root(root: &str) {
+    let _ = INPUT_ROOT.set(root.trim_end_matches('/').to_owned());
+}
+
+fn input_root() -> &'static str {
+    INPUT_ROOT.get().map_or(DEFAULT_INPUT_ROOT, |x| x)
+}
+
+// Where inputs lived before everyone could have their own
+fn shared_input_path(root: &str, year: usize, day_number: usize) -> String {
+    format!("{root}/{year}/day{day_number}.txt")
+}
+
+fn legacy_input_path(root: &str, day_number: usize) -> String {
+    format!("{root}/day{day_number}.txt")
+}
+
+fn person_input_path(root: &str, year: usize, by: &Person, day_number: usize, variant: &str) -> String {
+    format!("{root}/{year}/{}/day{day_number}/{variant}.txt", by.name())
+}
+
+pub fn variant_input_path(year: usize, by: &Person, day_number: usize, variant: &str) -> String {
+    person_input_path(input_root(), year, by, day_number, variant)
+}
+
+// Prefer the person's own file for the variant. Only the real input falls back to the shared file, then for 2024
+// the file from before there were years. The shared file is returned even if it doesn't exist so callers can say what's missing.
+pub fn resolve_input(year: usize, by: &Person, day_number: usize, variant: Option<&str>) -> Result<Input, String> {
+    resolve_input_in(input_root(), year, by, day_number, variant)
+}
+
+fn resolve_input_in(root: &str, year: usize, by: &Person, day_number: usize, variant: Option<&str>) -> Result<Input, String> {
+    let variant = variant.unwrap_or(DEFAULT_VARIANT);
+    let path = person_input_path(root, year, by, day_number, variant);
+    if Path::new(&path).exists() {
+        return Ok(Input { path, source: InputSource::Variant(variant.to_owned()), contents: None });
+    }
+    if variant != DEFAULT_VARIANT {
+        return Err(format!("There is no '{variant}' input for {} day {day_number} of {year}, expected {path}", by.name()));
+    }
+    let shared = shared_input_path(root, year, day_number);
+    let legacy = legacy_input_path(root, day_number);
+    if year == LEGACY_INPUT_YEAR && !Path::new(&shared).exists() && Path::new(&legacy).exists() {
+        return Ok(Input { path: legacy, source: InputSource::Legacy { missing: [path, shared] }, contents: None });
+    }
+    Ok(Input { path: shared, source: InputSource::Shared { missing: path }, contents: None })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    // An input root with these files in it, each holding its own path
+    fn temp_root(name: &str, files: &[&str]) -> String {
+        let root = std::env::temp_dir().join(format!("aoc2024-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in files {
+            let filepath = root.join(file);
+            fs::create_dir_all(filepath.parent().unwrap()).unwrap();
+            fs::write(filepath, file).unwrap();
+        }
+        root.to_str().unwrap().to_owned()
+    }
+
+    fn resolved(root: &str, year: usize, variant: Option<&str>) -> Result<(String, String), String> {
+        resolve_input_in(root, year, &Person::Aidan, 3, variant)
+            .map(|input| (input.path.strip_prefix(root).unwrap().to_owned(), input.source.to_string()))
+    }
+
+    #[test]
+    fn prefers_the_persons_own_variant() {
+        let root = temp_root("variant", &["2024/aidan/day3/real.txt", "2024/aidan/day3/example1.txt", "2024/day3.txt", "day3.txt"]);
+        assert_eq!(resolved(&root, 2024, None).unwrap(), ("/2024/aidan/day3/real.txt".to_owned(), "'real' variant".to_owned()));
+        assert_eq!(resolved(&root, 2024, Some("example1")).unwrap().0, "/2024/aidan/day3/example1.txt");
+        // Examples never fall back to the real input
+        let missing = resolved(&root, 2024, Some("example2")).unwrap_err();
+        assert!(missing.starts_with("There is no 'example2' input for aidan day 3 of 2024"), "{missing}");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_the_shared_input_then_for_2024_the_legacy_one() {
+        let root = temp_root("shared", &["2024/day3.txt", "day3.txt"]);
+        assert_eq!(resolved(&root, 2024, None).unwrap().0, "/2024/day3.txt");
+        fs::remove_file(format!("{root}/2024/day3.txt")).unwrap();
+        let (path, source) = resolved(&root, 2024, None).unwrap();
+        assert_eq!(path, "/day3.txt");
+        assert!(source.starts_with("shared input from before years"), "{source}");
+        // Other years never had inputs straight in the root, so the missing shared file is what gets reported
+        let (path, source) = resolved(&root, 2025, None).unwrap();
+        assert_eq!(path, "/2025/day3.txt");
+        assert!(source.starts_with("shared input, there is no"), "{source}");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,125 +1,655 @@
 
-use std::fs;
+use std::{collections::BTreeMap, sync::{mpsc, Mutex}, thread, time::{Duration, Instant}};
 
-use clap::{Parser, ValueEnum};
+use clap::{CommandFactory, Parser};
 
+use crate::baseline::*;
+use crate::bench::*;
+use crate::calendar::*;
+use crate::compare::*;
+use crate::config::*;
+use crate::crash::*;
 use crate::days::*;
+use crate::expected::*;
+use crate::history::*;
+use crate::input::*;
+use crate::output::*;
+use crate::report::*;
+use crate::scaffold::*;
+use crate::visualize::*;
+use crate::watch::*;
+mod alloc;
+mod baseline;
+mod bench;
+mod calendar;
+mod compare;
+mod config;
+mod crash;
 mod days;
+mod expected;
+mod history;
+mod input;
+mod output;
+mod report;
+mod scaffold;
+mod visualize;
+mod watch;
 
-const LAST_PERSON_FILE_PATH: &str = ".last";
+// `list` only needs to see which parts answer, not wait for slow ones to finish
+const LIST_TIMEOUT: Duration = Duration::from_secs(1);
 
-fn get_default_input_file_for_day(day_number: usize) -> String {
-    format!("data/day{day_number}.txt")
+// A day to run and the input to run it on
+struct SelectedDay {
+    year: usize,
+    day: usize,
+    sol: Box<dyn Solution>,
+    input: Input,
 }
 
-fn run_all_days(by: Person, do_perf: bool, times: usize, solutions_only: bool) {
-    // Sort the solutions by day number
-    let all_days = get_solutions(by);
-    let mut all_days = all_days.into_iter().collect::<Vec<(usize, Box<dyn Solution>)>>();
-    all_days.sort_by(|a, b| a.0.cmp(&b.0));
-
-    let mut total = 0;
-
-    // Run all solutions
-    for (day_number, sol) in all_days.iter_mut() {
-        let filepath = get_default_input_file_for_day(*day_number);
+fn solve_day(selected: &mut SelectedDay, part: Option<usize>, solutions_only: bool, result: &mut DayResult) {
+    let (sol, input) = (&mut selected.sol, &selected.input);
+    let solved = catch_panic(|| {
         if solutions_only {
-            total += print_answers(format!("Day {day_number:2}"), sol, &filepath);
+            (result.part1, result.part2) = get_answer(sol, input, part);
         } else {
-            println!("Executing for day {day_number} with {filepath}:");
-            run_day(sol, &filepath);
-            if do_perf {
-                run_many_times(sol, &filepath, times);
+            run_day(sol, input, part, result);
+        }
+    });
+    if let Err(crash) = solved {
+        result.part1 = if runs_part(part, 1) { Answer::Crashed } else { Answer::Skipped };
+        result.part2 = if runs_part(part, 2) { Answer::Crashed } else { Answer::Skipped };
+        result.times = None;
+        result.crash = Some(crash);
+    }
+}
+
+fn run_solution(by: &Person, mut selected: SelectedDay, part: Option<usize>, bench_config: Option<&BenchConfig>, solutions_only: bool, timeout: Option<Duration>) -> DayResult {
+    let mut result = DayResult::new(selected.year, by.clone(), selected.day, &selected.input);
+    // Benchmarking comes out of the same time as solving
+    let deadline = timeout.map(|x| Instant::now() + x);
+    match timeout {
+        None => solve_day(&mut selected, part, solutions_only, &mut result),
+        Some(timeout) => {
+            let mut timed = DayResult::new(selected.year, by.clone(), selected.day, &selected.input);
+            let finished = run_with_timeout(timeout, move || {
+                solve_day(&mut selected, part, solutions_only, &mut timed);
+                (selected, timed)
+            });
+            match finished {
+                Some((finished, timed)) => (selected, result) = (finished, timed),
+                None => {
+                    // The day never checked its deadline and is still running, so there's nothing to benchmark
+                    result.part1 = if runs_part(part, 1) { Answer::TimedOut } else { Answer::Skipped };
+                    result.part2 = if runs_part(part, 2) { Answer::TimedOut } else { Answer::Skipped };
+                    return result;
+                }
             }
-            println!();
         }
     }
 
-    if solutions_only {
-        println!("Completed [{}/{}]", total, all_days.len() * 2);
+    let finished = ![&result.part1, &result.part2].iter().any(|x| matches!(x, Answer::TimedOut | Answer::Crashed));
+    if let Some(config) = bench_config.filter(|_| !solutions_only && finished) {
+        result.benchmark = Some(run_benchmark(&mut selected.sol, &selected.input, part, config, deadline));
     }
+    result
 }
 
-#[derive(clap::Parser, Debug)]
-struct CLI {
-    #[arg(short='n', long, value_enum)]
-    person: Option<Person>,
-    #[arg(short, long, value_name = "INPUT FILE")]
-    input: Option<std::path::PathBuf>,
-    #[arg(short, long, value_name = "DAY NUMBER")]
+// The days to run, each with its input file
+fn select_days(year: usize, by: &Person, selection: &DaySelection) -> Result<Vec<SelectedDay>, String> {
+    let mut all_days = get_solutions(year, by.clone()).into_iter().collect::<Vec<(usize, Box<dyn Solution>)>>();
+    if selection.all {
+        // Sort the solutions by day number
+        all_days.sort_by_key(|x| x.0);
+        let mut days = vec![];
+        for (day, sol) in all_days {
+            match resolve_input(year, by, day, selection.variant.as_deref()) {
+                Ok(input) => days.push(SelectedDay { year, day, sol, input }),
+                // Not every day has every example
+                Err(e) => eprintln!("Skipping day {day}: {e}"),
+            }
+        }
+        return Ok(days);
+    }
+
+    let day = match selection.day.or_else(|| get_today(year)) {
+        Some(x) => x,
+        None => return Err(no_today_message(year)),
+    };
+    let input = match (&selection.input, &selection.input_text) {
+        (Some(path), _) => Input::from_arg(path)?,
+        (_, Some(text)) => Input::inline(text),
+        _ => resolve_input(year, by, day, selection.variant.as_deref())?,
+    };
+    match all_days.into_iter().find(|x| x.0 == day) {
+        Some((_, sol)) => Ok(vec![SelectedDay { year, day, sol, input }]),
+        None => Err(format!("Failed to find solution day {} of {}", day, year)),
+    }
+}
+
+fn run_days(by: Person, days: Vec<SelectedDay>, part: Option<usize>, bench_config: Option<&BenchConfig>, solutions_only: bool, timeout: Option<Duration>, reporter: &mut Reporter) -> Vec<DayResult> {
+    let mut results = vec![];
+    for selected in days {
+        let result = run_solution(&by, selected, part, bench_config, solutions_only, timeout);
+        reporter.day(&result);
+        results.push(result);
+    }
+    results
+}
+
+// Solve the days on `jobs` threads, still reporting them in day order
+fn run_days_parallel(by: Person, days: Vec<SelectedDay>, part: Option<usize>, jobs: usize, solutions_only: bool, timeout: Option<Duration>, reporter: &mut Reporter) -> Vec<DayResult> {
+    let workers = jobs.min(days.len());
+    let queue = Mutex::new(days.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+    let mut results = vec![];
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let (by, queue) = (&by, &queue);
+            scope.spawn(move || loop {
+                let Some((idx, selected)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let result = run_solution(by, selected, part, None, solutions_only, timeout);
+                if sender.send((idx, result)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Days finish in any order, so hold on to each one until every day before it is reported
+        let mut finished = BTreeMap::new();
+        for (idx, result) in receiver {
+            finished.insert(idx, result);
+            while let Some(result) = finished.remove(&results.len()) {
+                reporter.day(&result);
+                results.push(result);
+            }
+        }
+    });
+    results
+}
+
+// Every registered day, which parts are implemented and whether its input is there
+fn list_days(year: usize, by: Person, timeout: Duration) {
+    let mut all_days = get_solutions(year, by.clone()).into_iter().collect::<Vec<(usize, Box<dyn Solution>)>>();
+    all_days.sort_by_key(|x| x.0);
+
+    println!("Registered days of {} for {}:", year, by.name());
+    for (day, sol) in all_days {
+        let input = resolve_input(year, &by, day, None).unwrap();
+        if !input.exists() {
+            println!("Day {:2} [?] [?] => missing {} ({})", day, input.path, input.source);
+            continue;
+        }
+        // A part is only known to be implemented once it has run, so a broken or slow day can't hold up the rest
+        let (path, source) = (input.path.clone(), input.source.to_string());
+        let result = run_solution(&by, SelectedDay { year, day, sol, input }, None, None, true, Some(timeout));
+        let status = |answer: &Answer| match answer {
+            Answer::NotImplemented => ' ',
+            Answer::NotApplicable => '-',
+            Answer::TimedOut => '~',
+            Answer::Crashed => '!',
+            _ => '+',
+        };
+        println!("Day {:2} [{}] [{}] => {} ({})", day, status(&result.part1), status(&result.part2), path, source);
+    }
+    println!("[+] implemented, [ ] not implemented, [-] not applicable, [?] no input, [!] crashed, [~] still running after {timeout:?}");
+}
+
+// Save and compare benchmark baselines. Returns false if there was a regression.
+fn check_baselines(options: &BenchArgs, format: OutputFormat, results: &[DayResult]) -> std::io::Result<bool> {
+    // Keep machine-readable output clean
+    let mut out: Box<dyn std::io::Write> = match format {
+        OutputFormat::Text => Box::new(std::io::stdout()),
+        _ => Box::new(std::io::stderr()),
+    };
+
+    let mut passed = true;
+    if let Some(name) = &options.baseline {
+        match Baseline::load(name) {
+            Ok(baseline) => {
+                writeln!(out, "Comparing against baseline '{name}':")?;
+                passed = compare(&baseline, results, options.threshold, &mut out)? == 0;
+            }
+            Err(e) => {
+                writeln!(out, "{e}")?;
+                passed = false;
+            }
+        }
+    }
+    if let Some(name) = &options.save_baseline {
+        for result in results.iter().filter(|x| !x.puzzle_input) {
+            writeln!(out, "Day {:2}: not saved, {} isn't the real input", result.day, result.input)?;
+        }
+        match Baseline::from(results).save_over(name) {
+            Ok((filepath, 0)) => writeln!(out, "Saved baseline '{name}' to {filepath}")?,
+            Ok((filepath, kept)) => writeln!(out, "Saved baseline '{name}' to {filepath}, keeping the {kept} other {} it had", if kept == 1 { "day" } else { "days" })?,
+            Err(e) => {
+                writeln!(out, "{e}")?;
+                passed = false;
+            }
+        }
+    }
+    Ok(passed)
+}
+
+#[derive(clap::Args, Debug, Default)]
+struct DaySelection {
+    #[arg(short, long, value_name = "DAY NUMBER", value_parser = day_parser(), help = "Defaults to today")]
     day: Option<usize>,
-    #[arg(short, long, help = "Run all solutions")]
+    #[arg(short, long, conflicts_with_all = ["day", "input"], help = "Run all solutions")]
     all: bool,
-    #[arg(short, long, help = "Run many times to get average performance")]
-    performance: bool,
-    #[arg(short, long, default_value = "1000", help = "Number of times to run a solution for performance")]
-    times: usize,
+    #[arg(short, long, value_name = "INPUT FILE", help = "Use this file instead, or `-` to read stdin")]
+    input: Option<std::path::PathBuf>,
+    #[arg(long, value_name = "TEXT", conflicts_with_all = ["all", "input", "variant"], help = "Use this text as the input, like a pasted example")]
+    input_text: Option<String>,
+    #[arg(long, conflicts_with = "input", help = "Use data/<person>/dayN/<VARIANT>.txt, like example1 [default: real]")]
+    variant: Option<String>,
+    #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=2), help = "Only run part 1 or part 2")]
+    part: Option<usize>,
+}
+
+#[derive(clap::Args, Debug, Default)]
+struct RunArgs {
+    #[command(flatten)]
+    days: DaySelection,
     #[arg(short, long="solutions-only", help = "Just print the answers")]
     solutions_only: bool,
+    #[arg(short, long, default_value = "1", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), help = "Solve this many days at once (with --all)")]
+    jobs: usize,
+    #[arg(short, long, value_enum, help = "How to print the results [default: text]")]
+    format: Option<OutputFormat>,
+    #[arg(long, value_name = "SECONDS", help = "Give up on a day after this long and move on to the next")]
+    timeout: Option<f64>,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    days: DaySelection,
+    #[arg(short, long, conflicts_with = "bench_time", help = "Take exactly this many benchmark samples instead of sampling for --bench-time")]
+    times: Option<usize>,
+    #[arg(long, value_name = "SECONDS", help = "How long to keep taking benchmark samples [default: 2]")]
+    bench_time: Option<f64>,
+    #[arg(long, default_value = "0.5", value_name = "SECONDS", help = "How long to warm up before benchmarking")]
+    warmup: f64,
+    #[arg(short, long, value_enum, help = "How to print the results [default: text]")]
+    format: Option<OutputFormat>,
+    #[arg(long, value_name = "NAME", help = "Save the benchmark as a named baseline")]
+    save_baseline: Option<String>,
+    #[arg(long, value_name = "NAME", help = "Compare the benchmark against a named baseline")]
+    baseline: Option<String>,
+    #[arg(long, default_value = "5", value_name = "PERCENT", requires = "baseline", help = "How much slower than the baseline counts as a regression")]
+    threshold: f64,
+    #[arg(long, value_name = "SECONDS", help = "Give up on a day after this long and move on to the next. Benchmarking stops early to fit in it too")]
+    timeout: Option<f64>,
+}
+
+#[derive(clap::Args, Debug)]
+struct CompareArgs {
+    #[arg(short, long, value_name = "DAY NUMBER", value_parser = day_parser(), help = "Defaults to every day everyone has solved")]
+    day: Option<usize>,
+    #[arg(short, long, value_name = "INPUT FILE", requires = "day", help = "Use this file instead, or `-` to read stdin")]
+    input: Option<std::path::PathBuf>,
+    #[arg(long, conflicts_with = "input", help = "Use the selected person's data/<person>/dayN/<VARIANT>.txt [default: real]")]
+    variant: Option<String>,
+    #[arg(short, long, help = "Compare the mean of this many benchmark samples instead of a single run")]
+    times: Option<usize>,
+}
+
+#[derive(clap::Args, Debug)]
+struct VerifyArgs {
+    #[arg(long, help = "Record the current answers as the expected answers instead")]
+    record: bool,
+    #[arg(long, help = "Check the answers for data/<person>/dayN/<VARIANT>.txt [default: real]")]
+    variant: Option<String>,
+    #[arg(long, value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=2), help = "Only run part 1 or part 2")]
+    part: Option<usize>,
+}
+
+#[derive(clap::Args, Debug)]
+struct HistoryArgs {
+    #[arg(short, long, value_name = "DAY NUMBER", value_parser = day_parser(), help = "Defaults to today")]
+    day: Option<usize>,
+    #[arg(long, value_enum, default_value = "total", help = "Which phase to show")]
+    phase: Phase,
+    #[arg(long, default_value = "20", value_name = "RUNS", help = "Only show this many of the latest runs")]
+    last: usize,
+}
+
+#[derive(clap::Args, Debug)]
+struct ReportArgs {
+    #[arg(short, long, value_enum, default_value = "markdown", help = "What to write the report as")]
+    format: ReportFormat,
+    #[arg(short, long, value_name = "FILE", help = "Write the report here instead of printing it")]
+    output: Option<std::path::PathBuf>,
+    #[arg(long, help = "Run everyone on their data/<person>/dayN/<VARIANT>.txt [default: real]")]
+    variant: Option<String>,
+    #[arg(long, value_name = "SECONDS", help = "Give up on a day after this long and move on to the next")]
+    timeout: Option<f64>,
+}
+
+#[derive(clap::Args, Debug)]
+struct WatchArgs {
+    #[command(flatten)]
+    days: DaySelection,
+    #[arg(short, long, help = "Also rebuild and re-run when the solution's source changes")]
+    source: bool,
+    #[arg(long, default_value = "500", value_name = "MILLISECONDS", help = "How often to check for changes")]
+    interval: u64,
+}
+
+#[derive(clap::Args, Debug)]
+struct VisualizeArgs {
+    #[command(flatten)]
+    days: DaySelection,
+    #[arg(short, long, value_enum, default_value = "play", help = "Play the frames in the terminal, or save them as text files or images")]
+    format: FrameFormat,
+    #[arg(short, long, default_value = "frames", value_name = "DIRECTORY", help = "Where to save the frames")]
+    output: std::path::PathBuf,
+    #[arg(long, default_value = "50", value_name = "MILLISECONDS", help = "How long to show each frame when playing")]
+    delay: u64,
+    #[arg(long, default_value = "4", value_name = "PIXELS", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), help = "How big each cell is in images")]
+    scale: usize,
+    #[arg(long, default_value = "1", value_name = "FRAMES", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..), help = "Only keep every this many frames")]
+    every: usize,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    #[command(about = "Run a day, or all of them (the default command)")]
+    Run(RunArgs),
+    #[command(about = "Run and benchmark a day, or all of them")]
+    Bench(BenchArgs),
+    #[command(about = "Run a day again every time its input, or with --source its solution, changes")]
+    Watch(WatchArgs),
+    #[command(alias = "vis", about = "Run a day, playing or saving the frames it draws")]
+    Visualize(VisualizeArgs),
+    #[command(about = "Check every solution against the expected answers")]
+    Verify(VerifyArgs),
+    #[command(about = "Run everyone's solutions on the same input and compare their answers and timings")]
+    Compare(CompareArgs),
+    #[command(about = "Show how a day's benchmarked runtime changed over every `bench` run")]
+    History(HistoryArgs),
+    #[command(about = "Run everyone's days and write a Markdown or HTML report of their stars, answers and timings")]
+    Report(ReportArgs),
+    #[command(about = "List the registered days, their implemented parts and inputs")]
+    List {
+        #[arg(long, value_name = "SECONDS", help = "How long to give each day to show which parts answer [default: 1]")]
+        timeout: Option<f64>,
+    },
+    #[command(alias = "new-day", about = "Create the solution and input files for a day, leaving existing ones alone")]
+    New {
+        #[arg(short, long, value_name = "DAY NUMBER", value_parser = day_parser(), help = "Defaults to today")]
+        day: Option<usize>,
+        #[arg(short, long, conflicts_with_all = ["day", "year"], help = "Count down to the next puzzle, then create it")]
+        wait: bool,
+    },
+}
+
+#[derive(clap::Parser, Debug)]
+struct CLI {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(short='n', long, value_enum, global = true)]
+    person: Option<Person>,
+    #[arg(short, long, global = true, help = "Which Advent of Code [default: the latest one]")]
+    year: Option<usize>,
 }
 
 fn main() -> std::io::Result<()> {
     let options = CLI::parse();
+    let mut config = match Config::load() {
+        Ok(x) => x,
+        Err(e) => {
+            println!("{e}");
+            std::process::exit(1);
+        }
+    };
+    if config.person.is_none() {
+        match migrate_last_person() {
+            Ok(person) => config.person = person,
+            Err(e) => println!("{e}"),
+        }
+    }
 
     // Store the last person used so you don't need to set it every time :)
-    let person;
-    if options.person.is_some() {
-        person = options.person.unwrap();
-        fs::write(LAST_PERSON_FILE_PATH, format!("{:#?}", person))?;
+    if let Some(person) = &options.person {
+        if let Err(e) = save_setting(std::path::Path::new(PROJECT_CONFIG_FILE_PATH), "person", person.name()) {
+            println!("{e}");
+        }
     }
-    else {
-        let last_person = fs::read_to_string(LAST_PERSON_FILE_PATH);
-        match last_person {
-            Err(_) => {
-                println!("Must set `--person` the first time.");
+    let Some(person) = options.person.clone().or(config.person.clone()) else {
+        println!("Must set `--person` the first time.");
+        return Ok(());
+    };
+
+    let year = options.year.or(config.year).unwrap_or_else(get_default_year);
+    if let Some(root) = &config.input_root {
+        set_input_root(root);
+    }
+    let timeout = |flag: Option<f64>| flag.or(config.timeout).map(Duration::from_secs_f64);
+
+    match options.command.unwrap_or(Command::Run(RunArgs::default())) {
+        Command::Run(args) => {
+            // Only one day can't be split over threads
+            if args.jobs > 1 && !args.days.all {
+                CLI::command().error(clap::error::ErrorKind::MissingRequiredArgument, "`--jobs` needs `--all`").exit();
+            }
+            let days = match select_days(year, &person, &args.days) {
+                Ok(x) => x,
+                Err(e) => {
+                    println!("{e}");
+                    return Ok(());
+                }
+            };
+            let timeout = timeout(args.timeout);
+            let mut reporter = Reporter::new(args.format.or(config.format).unwrap_or_default(), args.days.all && args.solutions_only);
+            if args.jobs > 1 {
+                run_days_parallel(person, days, args.days.part, args.jobs, args.solutions_only, timeout, &mut reporter);
+            } else {
+                run_days(person, days, args.days.part, None, args.solutions_only, timeout, &mut reporter);
+            }
+            reporter.finish();
+        }
+        Command::Bench(args) => {
+            let days = match select_days(year, &person, &args.days) {
+                Ok(x) => x,
+                Err(e) => {
+                    println!("{e}");
+                    return Ok(());
+                }
+            };
+            let bench_config = BenchConfig {
+                warmup: Duration::from_secs_f64(args.warmup),
+                // A configured sample count only applies when neither flag is given
+                samples: match (args.times, args.bench_time, config.bench_samples) {
+                    (Some(times), _, _) => Samples::Fixed(times),
+                    (None, Some(seconds), _) => Samples::Timed(Duration::from_secs_f64(seconds)),
+                    (None, None, Some(times)) => Samples::Fixed(times),
+                    (None, None, None) => Samples::Timed(Duration::from_secs(2)),
+                },
+            };
+            let format = args.format.or(config.format).unwrap_or_default();
+            let mut reporter = Reporter::new(format, false);
+            let results = run_days(person, days, args.days.part, Some(&bench_config), false, timeout(args.timeout), &mut reporter);
+            reporter.finish();
+            if let Err(e) = append_history(&results) {
+                eprintln!("{e}");
+            }
+            if !check_baselines(&args, format, &results)? {
+                std::process::exit(1);
+            }
+        }
+        Command::Watch(args) => {
+            // Watching every day would re-run all of them on any change
+            if args.days.all {
+                CLI::command().error(clap::error::ErrorKind::ArgumentConflict, "`watch` runs a single day, not `--all`").exit();
+            }
+            let selected = match select_days(year, &person, &args.days) {
+                Ok(x) => x.into_iter().next().unwrap(),
+                Err(e) => {
+                    println!("{e}");
+                    return Ok(());
+                }
+            };
+            if !selected.input.is_file() {
+                println!("`watch` needs an input file to watch");
                 return Ok(());
             }
-            Ok(last_person) => {
-                let last_person = Person::from_str(&last_person, true);
-                if last_person.is_err() {
-                    println!("Failed to read the last person. Must set `--person`.");
+            let options = WatchOptions { part: args.days.part, source: args.source, interval: Duration::from_millis(args.interval) };
+            watch(selected.year, person, selected.day, selected.sol, selected.input, &options);
+        }
+        Command::Visualize(args) => {
+            if args.days.all {
+                CLI::command().error(clap::error::ErrorKind::ArgumentConflict, "`visualize` runs a single day, not `--all`").exit();
+            }
+            let mut selected = match select_days(year, &person, &args.days) {
+                Ok(x) => x.into_iter().next().unwrap(),
+                Err(e) => {
+                    println!("{e}");
                     return Ok(());
                 }
-                person = last_person.unwrap();
+            };
+            let recording = Recording {
+                format: args.format,
+                directory: args.output.clone(),
+                delay: Duration::from_millis(args.delay),
+                scale: args.scale,
+                every: args.every,
+            };
+            let (answers, recorded) = record(&recording, || catch_panic(|| get_answer(&mut selected.sol, &selected.input, args.days.part)));
+            match answers {
+                Ok((part1, part2)) => println!("Day {}: {part1} / {part2}", selected.day),
+                Err(crash) => println!("Day {} crashed: {crash}", selected.day),
+            }
+            match recorded {
+                Ok(recorded) if recorded.emitted == 0 => println!("Day {} doesn't draw any frames. Its parts can `emit` anything that implements `Visualize`.", selected.day),
+                Ok(recorded) if args.format == FrameFormat::Play => println!("Played {} of {} frames", recorded.shown, recorded.emitted),
+                Ok(recorded) => println!("Saved {} of {} frames to {}", recorded.shown, recorded.emitted, args.output.display()),
+                Err(e) => {
+                    println!("{e}");
+                    std::process::exit(1);
+                }
             }
         }
-    }
-
-    // If we're running them all, we can ignore the other inputs
-    if options.all {
-        run_all_days(person, options.performance, options.times, options.solutions_only);
-    } else {
-        let day_number = match options.day {
-            Some(x) => x,
-            None => match days::get_today() {
-                Some(x) => x,
-                None => {
-                    println!("Failed to get today. Must set `--day`.");
+        Command::Verify(args) => {
+            let mut expected = match ExpectedAnswers::load(EXPECTED_ANSWERS_FILE_PATH) {
+                Ok(x) => x,
+                Err(e) => {
+                    println!("{e}");
+                    std::process::exit(1);
+                }
+            };
+            if args.record {
+                record_all(year, person, args.variant.as_deref(), args.part, &mut expected);
+                expected.save(EXPECTED_ANSWERS_FILE_PATH)?;
+                println!("Saved expected answers to {EXPECTED_ANSWERS_FILE_PATH}");
+            } else if !verify_all(year, person, args.variant.as_deref(), args.part, &expected) {
+                std::process::exit(1);
+            }
+        }
+        Command::Compare(args) => {
+            let people = everyone();
+            if let Some(day_number) = args.day {
+                if let Some(missing) = people.iter().find(|x| !get_solutions(year, (*x).clone()).contains_key(&day_number)) {
+                    println!("{} hasn't solved day {day_number}", missing.name());
                     return Ok(());
                 }
             }
-        };
-
-        let filepath = match options.input {
-            Some(path) => path.to_str().unwrap().to_owned(),
-            None => get_default_input_file_for_day(day_number)
-        };
+            let day_numbers = match args.day {
+                Some(day_number) => vec![day_number],
+                None => common_days(year, &people),
+            };
+            if day_numbers.is_empty() {
+                let names = people.iter().map(|x| x.name()).collect::<Vec<&str>>().join(", ");
+                println!("There are no days of {year} that everyone ({names}) has solved, so there's nothing to compare");
+                std::process::exit(1);
+            }
+            // Everyone gets the selected person's input
+            let mut days = vec![];
+            for day_number in day_numbers {
+                let input = match &args.input {
+                    Some(path) => Input::from_arg(path),
+                    None => resolve_input(year, &person, day_number, args.variant.as_deref()),
+                };
+                match input {
+                    Ok(input) => days.push((day_number, input)),
+                    Err(e) => println!("Skipping day {day_number}: {e}"),
+                }
+            }
 
-        let mut all_days = get_solutions(person);
-        let sol = all_days.get_mut(&day_number);
-        if sol.is_none() {
-            println!("Failed to find solution day {}", day_number);
-            return Ok(());
+            let bench_config = args.times.map(|times| BenchConfig { warmup: Duration::ZERO, samples: Samples::Fixed(times) });
+            if !compare_people(year, &people, &days, bench_config.as_ref()) {
+                std::process::exit(1);
+            }
+        }
+        Command::History(args) => {
+            let Some(day_number) = args.day.or_else(|| get_today(year)) else {
+                println!("{}", no_today_message(year));
+                return Ok(());
+            };
+            match load_history() {
+                Ok(entries) => print_history(&entries, year, &person, day_number, args.phase, args.last),
+                Err(e) => {
+                    println!("{e}");
+                    std::process::exit(1);
+                }
+            }
         }
-        let sol = sol.unwrap();
+        Command::Report(args) => {
+            let selection = DaySelection { all: true, variant: args.variant.clone(), ..Default::default() };
+            let mut people = vec![];
+            for by in everyone() {
+                let mut results = vec![];
+                for selected in select_days(year, &by, &selection).unwrap_or_default() {
+                    if !selected.input.exists() {
+                        eprintln!("Skipping {} day {}: missing {}", by.name(), selected.day, selected.input.path);
+                        continue;
+                    }
+                    results.push(run_solution(&by, selected, None, None, false, timeout(args.timeout)));
+                }
+                people.push((by, results));
+            }
 
-        println!("Executing day {day_number} with {filepath}:");
-        if options.solutions_only {
-            print_answers(format!("Day {day_number} with {filepath}"), sol, &filepath);
-        } else {
-            run_day(sol, &filepath);
-            if options.performance {
-                run_many_times(sol, &filepath, options.times);
+            let report = render(year, &people, args.format);
+            match &args.output {
+                Some(filepath) => {
+                    std::fs::write(filepath, report)?;
+                    println!("Saved the report to {}", filepath.display());
+                }
+                None => print!("{report}"),
+            }
+        }
+        Command::List { timeout: seconds } => list_days(year, person, timeout(seconds).unwrap_or(LIST_TIMEOUT)),
+        Command::New { day, wait } => {
+            let (year, day_number) = if wait {
+                wait_for_next_unlock()
+            } else {
+                match day.or_else(|| get_today(year)) {
+                    Some(x) => (year, x),
+                    None => {
+                        println!("{}", no_today_message(year));
+                        return Ok(());
+                    }
+                }
+            };
+            match new_day(std::path::Path::new(""), year, &person, day_number) {
+                Ok(files) => {
+                    for file in files {
+                        match file {
+                            Scaffolded::Created(path) => println!("Created {}", path.display()),
+                            Scaffolded::AlreadyExists(path) => println!("{} already exists, leaving it alone", path.display()),
+                        }
+                    }
+                }
+                Err(e) => {
+                    println!("{e}");
+                    std::process::exit(1);
+                }
             }
         }
     }
@@ -0,0 +1,376 @@
+use std::time::Duration;
+
+use crate::alloc::{self, get_formatted_bytes, AllocStats};
+use crate::bench::{print_benchmark, Benchmark, PhaseTimes, Stats};
+use crate::days::{get_formatted_time, Answer, Person};
+use crate::input::Input;
+
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+// Everything we learned from running a single day. Every output format renders this.
+pub struct DayResult {
+    pub year: usize,
+    pub person: Person,
+    pub day: usize,
+    pub input: String,
+    // Why that input file was picked
+    pub input_source: String,
+    // Only runs on the real puzzle input are kept in baselines and the history
+    pub puzzle_input: bool,
+    pub part1: Answer,
+    pub part2: Answer,
+    // Only set when the day was timed
+    pub times: Option<PhaseTimes>,
+    // Only set when the day was benchmarked
+    pub benchmark: Option<Benchmark>,
+    // The panic message and location, if the day panicked
+    pub crash: Option<String>,
+}
+
+impl DayResult {
+    pub fn new(year: usize, person: Person, day: usize, input: &Input) -> DayResult {
+        DayResult {
+            year,
+            person,
+            day,
+            input: input.path.clone(),
+            input_source: input.source.to_string(),
+            puzzle_input: input.source.is_puzzle_input(),
+            part1: Answer::NotImplemented,
+            part2: Answer::NotImplemented,
+            times: None,
+            benchmark: None,
+            crash: None,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        [&self.part1, &self.part2].iter().filter(|x| x.is_complete()).count()
+    }
+
+    pub fn available_stars(&self) -> usize {
+        [&self.part1, &self.part2].iter().filter(|x| x.is_applicable()).count()
+    }
+}
+
+// Renders day results as they finish
+pub struct Reporter {
+    format: OutputFormat,
+    // Print the star tally at the end of text output
+    summarize: bool,
+    records: usize,
+    stars: usize,
+    available_stars: usize,
+    // (day, panic) of every day that crashed, summarized at the end
+    crashes: Vec<(usize, String)>,
+}
+
+const PHASES: [&str; 5] = ["parse", "prepare", "part1", "part2", "total"];
+
+impl Reporter {
+    pub fn new(format: OutputFormat, summarize: bool) -> Reporter {
+        match format {
+            OutputFormat::Text => {}
+            OutputFormat::Json => println!("["),
+            OutputFormat::Csv => println!("{}", csv_header()),
+        }
+        Reporter { format, summarize, records: 0, stars: 0, available_stars: 0, crashes: vec![] }
+    }
+
+    pub fn day(&mut self, result: &DayResult) {
+        match self.format {
+            OutputFormat::Text => print_text(result),
+            OutputFormat::Json => println!("{}{}", if self.records > 0 { "," } else { "" }, json_record(result)),
+            OutputFormat::Csv => println!("{}", csv_record(result)),
+        }
+        self.records += 1;
+        self.stars += result.stars();
+        self.available_stars += result.available_stars();
+        if let Some(crash) = &result.crash {
+            self.crashes.push((result.day, crash.clone()));
+        }
+    }
+
+    pub fn finish(self) {
+        match self.format {
+            OutputFormat::Text => {
+                if self.summarize {
+                    println!("Completed [{}/{}]", self.stars, self.available_stars);
+                }
+            }
+            OutputFormat::Json => println!("]"),
+            OutputFormat::Csv => {}
+        }
+
+        // A single day already said why it crashed
+        if self.crashes.is_empty() || self.records == 1 {
+            return;
+        }
+        // Keep machine-readable output clean
+        let summary = format!(
+            "{} crashed:\n{}",
+            if self.crashes.len() == 1 { "1 day".to_owned() } else { format!("{} days", self.crashes.len()) },
+            self.crashes.iter().map(|(day, crash)| format!("| Day {day:2}: {crash}")).collect::<Vec<String>>().join("\n"),
+        );
+        match self.format {
+            OutputFormat::Text => println!("{summary}"),
+            _ => eprintln!("{summary}"),
+        }
+    }
+}
+
+fn print_text(result: &DayResult) {
+    let Some(times) = &result.times else {
+        print_answers(result);
+        if let Some(crash) = &result.crash {
+            println!("|   Crashed: {crash}");
+        }
+        return;
+    };
+
+    let total_time = times.total();
+    println!("Executing day {} with {} ({}):", result.day, result.input, result.input_source);
+    println!("Part 1: {}", result.part1);
+    println!("Part 2: {}", result.part2);
+    println!("Duration: {} ({}us){}", get_formatted_time(&total_time), total_time.as_micros(), text_allocs(&times.allocs.total()));
+    println!("|   Parsing: {}{}", get_formatted_time(&times.parse), text_allocs(&times.allocs.parse));
+    println!("|   Prepare: {}{}", get_formatted_time(&times.prepare), text_allocs(&times.allocs.prepare));
+    for (name, answer, time, allocs) in [("Part 1", &result.part1, &times.part1, &times.allocs.part1), ("Part 2", &result.part2, &times.part2, &times.allocs.part2)] {
+        if *answer == Answer::Skipped {
+            println!("| {:>9}: skipped", name);
+        } else {
+            println!("| {:>9}: {}{}", name, get_formatted_time(time), text_allocs(allocs));
+        }
+    }
+    if let Some(benchmark) = &result.benchmark {
+        print_benchmark(benchmark);
+    }
+    println!();
+}
+
+// Shown after a timing, only when allocations are counted
+fn text_allocs(stats: &AllocStats) -> String {
+    if !alloc::ENABLED {
+        return String::new();
+    }
+    format!(" ({} allocations, {} allocated, {} peak)", stats.allocations, get_formatted_bytes(stats.bytes), get_formatted_bytes(stats.peak))
+}
+
+pub fn print_answers(result: &DayResult) {
+    println!("Day {:2} [{}] [{}] => {}, {}", result.day, result.part1.star(), result.part2.star(), result.part1, result.part2);
+}
+
+fn answer_value(answer: &Answer) -> Option<String> {
+    answer.is_complete().then(|| answer.to_string())
+}
+
+fn json_string(value: &str) -> String {
+    let mut result = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or("null".to_owned(), |x| x.to_string())
+}
+
+fn json_answer(answer: &Answer) -> String {
+    format!(
+        "{{\"answer\":{},\"state\":\"{}\"}}",
+        json_optional(answer_value(answer).map(|x| json_string(&x))),
+        answer.state(),
+    )
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        "{{\"mean_ns\":{},\"median_ns\":{},\"p95_ns\":{},\"stddev_ns\":{},\"min_ns\":{},\"max_ns\":{},\"outliers\":{}}}",
+        stats.mean.as_nanos(), stats.median.as_nanos(), stats.p95.as_nanos(), stats.stddev.as_nanos(),
+        stats.min.as_nanos(), stats.max.as_nanos(), stats.outliers,
+    )
+}
+
+fn phase_times(times: &PhaseTimes) -> [Duration; 5] {
+    [times.parse, times.prepare, times.part1, times.part2, times.total()]
+}
+
+fn phase_allocs(times: &PhaseTimes) -> [AllocStats; 5] {
+    let allocs = &times.allocs;
+    [allocs.parse, allocs.prepare, allocs.part1, allocs.part2, allocs.total()]
+}
+
+fn phase_stats(benchmark: &Benchmark) -> [Option<&Stats>; 5] {
+    [Some(&benchmark.parse), Some(&benchmark.prepare), benchmark.part1.as_ref(), benchmark.part2.as_ref(), benchmark.total.as_ref()]
+}
+
+pub fn json_record(result: &DayResult) -> String {
+    let times = result.times.as_ref().map(|times| {
+        let fields = PHASES.iter().zip(phase_times(times))
+            .map(|(name, time)| format!("\"{name}_ns\":{}", time.as_nanos()))
+            .collect::<Vec<String>>();
+        format!("{{{}}}", fields.join(","))
+    });
+    let allocations = result.times.as_ref().filter(|_| alloc::ENABLED).map(|times| {
+        let fields = PHASES.iter().zip(phase_allocs(times))
+            .map(|(name, stats)| format!("\"{name}\":{{\"allocations\":{},\"bytes\":{},\"peak_bytes\":{}}}", stats.allocations, stats.bytes, stats.peak))
+            .collect::<Vec<String>>();
+        format!("{{{}}}", fields.join(","))
+    });
+    let benchmark = result.benchmark.as_ref().map(|benchmark| {
+        let phases = PHASES.iter().zip(phase_stats(benchmark))
+            .map(|(name, stats)| format!("\"{name}\":{}", json_optional(stats.map(json_stats))))
+            .collect::<Vec<String>>();
+        format!("{{\"samples\":{},\"warmup_runs\":{},{}}}", benchmark.samples, benchmark.warmup_runs, phases.join(","))
+    });
+
+    format!(
+        "{{\"year\":{},\"person\":{},\"day\":{},\"input\":{},\"input_source\":{},\"part1\":{},\"part2\":{},\"stars\":{},\"timings\":{},\"allocations\":{},\"benchmark\":{},\"crash\":{}}}",
+        result.year,
+        json_string(result.person.name()),
+        result.day,
+        json_string(&result.input),
+        json_string(&result.input_source),
+        json_answer(&result.part1),
+        json_answer(&result.part2),
+        result.stars(),
+        json_optional(times),
+        json_optional(allocations),
+        json_optional(benchmark),
+        json_optional(result.crash.as_deref().map(json_string)),
+    )
+}
+
+const ALLOC_COLUMNS: [&str; 3] = ["allocations", "alloc_bytes", "peak_bytes"];
+
+const STAT_COLUMNS: [&str; 7] = ["mean_ns", "median_ns", "p95_ns", "stddev_ns", "min_ns", "max_ns", "outliers"];
+
+fn csv_header() -> String {
+    let mut columns = vec!["year", "person", "day", "input", "input_source", "part1", "part1_state", "part2", "part2_state", "stars"]
+        .into_iter().map(|x| x.to_owned()).collect::<Vec<String>>();
+    columns.extend(PHASES.iter().map(|phase| format!("{phase}_ns")));
+    for phase in PHASES {
+        columns.extend(ALLOC_COLUMNS.iter().map(|column| format!("{phase}_{column}")));
+    }
+    columns.push("bench_samples".to_owned());
+    for phase in PHASES {
+        columns.extend(STAT_COLUMNS.iter().map(|stat| format!("bench_{phase}_{stat}")));
+    }
+    columns.push("crash".to_owned());
+    columns.join(",")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+pub fn csv_record(result: &DayResult) -> String {
+    let mut fields = vec![
+        result.year.to_string(),
+        result.person.name().to_owned(),
+        result.day.to_string(),
+        csv_field(&result.input),
+        csv_field(&result.input_source),
+        csv_field(&answer_value(&result.part1).unwrap_or_default()),
+        result.part1.state().to_owned(),
+        csv_field(&answer_value(&result.part2).unwrap_or_default()),
+        result.part2.state().to_owned(),
+        result.stars().to_string(),
+    ];
+    match &result.times {
+        Some(times) => fields.extend(phase_times(times).iter().map(|x| x.as_nanos().to_string())),
+        None => fields.extend(PHASES.iter().map(|_| String::new())),
+    }
+    match result.times.as_ref().filter(|_| alloc::ENABLED) {
+        Some(times) => {
+            for stats in phase_allocs(times) {
+                fields.extend([stats.allocations, stats.bytes, stats.peak].iter().map(|x| x.to_string()));
+            }
+        }
+        None => fields.extend((0..PHASES.len() * ALLOC_COLUMNS.len()).map(|_| String::new())),
+    }
+    match &result.benchmark {
+        Some(benchmark) => {
+            fields.push(benchmark.samples.to_string());
+            for stats in phase_stats(benchmark) {
+                let Some(stats) = stats else {
+                    fields.extend(STAT_COLUMNS.iter().map(|_| String::new()));
+                    continue;
+                };
+                fields.extend([stats.mean, stats.median, stats.p95, stats.stddev, stats.min, stats.max].iter().map(|x| x.as_nanos().to_string()));
+                fields.push(stats.outliers.to_string());
+            }
+        }
+        None => fields.extend((0..1 + PHASES.len() * STAT_COLUMNS.len()).map(|_| String::new())),
+    }
+    fields.push(csv_field(result.crash.as_deref().unwrap_or_default()));
+    fields.join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Split a CSV line on the commas outside of quotes
+    fn csv_columns(line: &str) -> usize {
+        let mut quoted = false;
+        1 + line.chars().filter(|c| {
+            if *c == '"' {
+                quoted = !quoted;
+            }
+            *c == ',' && !quoted
+        }).count()
+    }
+
+    #[test]
+    fn csv_records_match_the_header() {
+        let times = PhaseTimes { parse: Duration::from_micros(5), part1: Duration::from_micros(7), ..Default::default() };
+        let stats = Stats::from(&[Duration::from_micros(3), Duration::from_micros(4)]);
+        let input = Input::inline("1,2");
+
+        let timed = DayResult { part1: Answer::from(1), part2: Answer::from("a,\"b\""), times: Some(times), ..DayResult::new(2024, Person::Aidan, 1, &input) };
+        let benchmarked = DayResult {
+            benchmark: Some(Benchmark { warmup_runs: 1, samples: 2, cut_short: false, parse: stats.clone(), prepare: stats.clone(), part1: Some(stats.clone()), part2: Some(stats.clone()), total: Some(stats.clone()) }),
+            ..DayResult::new(2024, Person::Aidan, 2, &input)
+        };
+        let skipped = DayResult {
+            part2: Answer::Skipped,
+            benchmark: Some(Benchmark { warmup_runs: 1, samples: 2, cut_short: false, parse: stats.clone(), prepare: stats.clone(), part1: Some(stats.clone()), part2: None, total: None }),
+            ..DayResult::new(2024, Person::Will, 3, &input)
+        };
+        let crashed = DayResult { part1: Answer::Crashed, part2: Answer::Crashed, crash: Some("bad \"input\", line 2\nat src/x.rs:1:1".to_owned()), ..DayResult::new(2024, Person::Will, 4, &input) };
+
+        let columns = csv_columns(&csv_header());
+        for result in [timed, benchmarked, skipped, crashed] {
+            let record = csv_record(&result);
+            assert_eq!(csv_columns(&record), columns, "day {}: {record}", result.day);
+        }
+    }
+
+    #[test]
+    fn json_strings_are_escaped() {
+        assert_eq!(json_string("a\"b\\c\nd\te\u{1}é"), "\"a\\\"b\\\\c\\nd\\te\\u0001é\"");
+        let crashed = DayResult { crash: Some("called `unwrap()` on \"x\"".to_owned()), ..DayResult::new(2024, Person::Aidan, 1, &Input::inline("")) };
+        assert!(json_record(&crashed).ends_with(",\"crash\":\"called `unwrap()` on \\\"x\\\"\"}"));
+    }
+}
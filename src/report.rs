@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use crate::days::{get_formatted_time, Person, LAST_DAY};
+use crate::output::DayResult;
+
+// How many of each person's days get highlighted as the slowest
+const SLOWEST_DAYS: usize = 3;
+
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    // A single page with its styles inlined, so it can be shared on its own
+    Html,
+}
+
+struct Row {
+    cells: Vec<String>,
+    highlight: bool,
+}
+
+struct Table {
+    header: Vec<String>,
+    rows: Vec<Row>,
+}
+
+// Every report is headings, paragraphs and tables, rendered the same way in each format
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    Table(Table),
+}
+
+fn stars(result: Option<&DayResult>) -> String {
+    match result {
+        None => String::new(),
+        Some(result) if result.stars() == 0 => "☆".to_owned(),
+        Some(result) => "★".repeat(result.stars()),
+    }
+}
+
+// A grid of everyone's stars, like the calendar on the website
+fn star_grid(people: &[(Person, Vec<DayResult>)]) -> Table {
+    let mut header = vec!["".to_owned()];
+    header.extend((1..=LAST_DAY).map(|day| day.to_string()));
+    header.push("Stars".to_owned());
+    let rows = people.iter().map(|(person, results)| {
+        let mut cells = vec![person.name().to_owned()];
+        cells.extend((1..=LAST_DAY).map(|day| stars(results.iter().find(|x| x.day == day))));
+        cells.push(results.iter().map(|x| x.stars()).sum::<usize>().to_string());
+        Row { cells, highlight: false }
+    }).collect();
+    Table { header, rows }
+}
+
+fn total_time(result: &DayResult) -> Option<Duration> {
+    result.times.as_ref().map(|x| x.total())
+}
+
+// The days to highlight. With only a few timed days, highlighting them all wouldn't single anything out.
+fn slowest_days(results: &[DayResult]) -> Vec<usize> {
+    let mut by_time = results.iter().filter_map(|x| Some((x.day, total_time(x)?))).collect::<Vec<(usize, Duration)>>();
+    if by_time.len() <= SLOWEST_DAYS {
+        return vec![];
+    }
+    by_time.sort_by_key(|x| std::cmp::Reverse(x.1));
+    by_time.iter().take(SLOWEST_DAYS).map(|x| x.0).collect()
+}
+
+fn day_table(results: &[DayResult]) -> Table {
+    let slowest = slowest_days(results);
+
+    let header = ["Day", "Part 1", "Part 2", "Parsing", "Prepare", "Part 1 time", "Part 2 time", "Total"].map(|x| x.to_owned()).to_vec();
+    let rows = results.iter().map(|result| {
+        let mut cells = vec![result.day.to_string(), result.part1.to_string(), result.part2.to_string()];
+        match &result.times {
+            Some(times) => cells.extend([times.parse, times.prepare, times.part1, times.part2, times.total()].iter().map(get_formatted_time)),
+            None => cells.extend((0..5).map(|_| "-".to_owned())),
+        }
+        Row { cells, highlight: slowest.contains(&result.day) }
+    }).collect();
+    Table { header, rows }
+}
+
+fn blocks(year: usize, people: &[(Person, Vec<DayResult>)]) -> Vec<Block> {
+    let mut blocks = vec![
+        Block::Heading(format!("Advent of Code {year}")),
+        Block::Table(star_grid(people)),
+    ];
+    for (person, results) in people {
+        blocks.push(Block::Heading(person.name().to_owned()));
+        if results.is_empty() {
+            blocks.push(Block::Paragraph("No days with an input to run.".to_owned()));
+            continue;
+        }
+        let total = results.iter().filter_map(total_time).sum::<Duration>();
+        let stars = results.iter().map(|x| x.stars()).sum::<usize>();
+        let available_stars = results.iter().map(|x| x.available_stars()).sum::<usize>();
+        let highlighted = if slowest_days(results).is_empty() { String::new() } else { format!(" The {SLOWEST_DAYS} slowest days are highlighted.") };
+        blocks.push(Block::Paragraph(format!(
+            "{stars}/{available_stars} stars from {} days in {} total.{highlighted}",
+            results.len(), get_formatted_time(&total),
+        )));
+        blocks.push(Block::Table(day_table(results)));
+    }
+    blocks
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+fn render_markdown(blocks: &[Block]) -> String {
+    let mut out = String::new();
+    for (idx, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Heading(text) => out.push_str(&format!("{} {text}\n\n", if idx == 0 { "#" } else { "##" })),
+            Block::Paragraph(text) => out.push_str(&format!("{text}\n\n")),
+            Block::Table(table) => {
+                out.push_str(&format!("| {} |\n", table.header.join(" | ")));
+                out.push_str(&format!("|{}\n", table.header.iter().map(|_| "---|").collect::<String>()));
+                for row in &table.rows {
+                    let cells = row.cells.iter().map(|x| match (row.highlight, x.is_empty()) {
+                        (true, false) => format!("**{}**", markdown_cell(x)),
+                        _ => markdown_cell(x),
+                    });
+                    out.push_str(&format!("| {} |\n", cells.collect::<Vec<String>>().join(" | ")));
+                }
+                out.push('\n');
+            }
+        }
+    }
+    out
+}
+
+fn html_escape(value: &str) -> String {
+    value.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+const HTML_STYLE: &str = "body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em; }
+h1, h2 { color: #00cc00; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #333340; padding: 0.2em 0.6em; text-align: right; }
+th { color: #ffffff; }
+td { color: #ffff66; }
+tr.slow td { background: #3a1010; color: #ff6666; font-weight: bold; }";
+
+fn render_html(year: usize, blocks: &[Block]) -> String {
+    let mut out = format!("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code {year}</title>\n<style>\n{HTML_STYLE}\n</style>\n</head>\n<body>\n");
+    for (idx, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Heading(text) => {
+                let tag = if idx == 0 { "h1" } else { "h2" };
+                out.push_str(&format!("<{tag}>{}</{tag}>\n", html_escape(text)));
+            }
+            Block::Paragraph(text) => out.push_str(&format!("<p>{}</p>\n", html_escape(text))),
+            Block::Table(table) => {
+                out.push_str("<table>\n<tr>");
+                for cell in &table.header {
+                    out.push_str(&format!("<th>{}</th>", html_escape(cell)));
+                }
+                out.push_str("</tr>\n");
+                for row in &table.rows {
+                    out.push_str(if row.highlight { "<tr class=\"slow\">" } else { "<tr>" });
+                    for cell in &row.cells {
+                        out.push_str(&format!("<td>{}</td>", html_escape(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+        }
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+// Render the results of running everyone's days
+pub fn render(year: usize, people: &[(Person, Vec<DayResult>)], format: ReportFormat) -> String {
+    let blocks = blocks(year, people);
+    match format {
+        ReportFormat::Markdown => render_markdown(&blocks),
+        ReportFormat::Html => render_html(year, &blocks),
+    }
+}
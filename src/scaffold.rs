@@ -0,0 +1,95 @@
+use std::{fs, io::{ErrorKind, Write}, path::{Path, PathBuf}};
+
+use crate::days::{Person, LAST_DAY};
+use crate::input::{variant_input_path, DEFAULT_VARIANT};
+
+const TEMPLATE: &str = include_str!("days/blankday.rs");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Scaffolded {
+    Created(PathBuf),
+    // Never overwrite existing work
+    AlreadyExists(PathBuf),
+}
+
+// Creates `path` with `contents` only if it doesn't exist yet
+fn create_new(path: &Path, contents: &str) -> Result<Scaffolded, String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {e}", parent.display()))?;
+    }
+    match fs::OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes()).map_err(|e| format!("Failed to write {}: {e}", path.display()))?;
+            Ok(Scaffolded::Created(path.to_owned()))
+        }
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(Scaffolded::AlreadyExists(path.to_owned())),
+        Err(e) => Err(format!("Failed to create {}: {e}", path.display())),
+    }
+}
+
+// Adds a day for someone inside the project at `root`, starting the year if it's new.
+// `build.rs` registers the new module. Running it again for the same day changes nothing.
+pub fn new_day(root: &Path, year: usize, person: &Person, day_number: usize) -> Result<Vec<Scaffolded>, String> {
+    if day_number == 0 || day_number > LAST_DAY {
+        return Err(format!("Day {day_number} is not between 1 and {LAST_DAY}"));
+    }
+
+    let person_dir = root.join("src/days").join(year.to_string()).join(person.name());
+    let solution = TEMPLATE.replace("DayNUMBER", &format!("Day{day_number}"));
+    let input_file = root.join(variant_input_path(year, person, day_number, DEFAULT_VARIANT));
+    Ok(vec![
+        create_new(&person_dir.join(format!("day{day_number}.rs")), &solution)?,
+        create_new(&input_file, "")?,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_project(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc2024-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    #[test]
+    fn creates_the_day_and_its_input() {
+        let root = temp_project("creates");
+        let result = new_day(&root, 2024, &Person::Will, 7).unwrap();
+        assert_eq!(result, vec![
+            Scaffolded::Created(root.join("src/days/2024/will/day7.rs")),
+            Scaffolded::Created(root.join("data/2024/will/day7/real.txt")),
+        ]);
+        let solution = fs::read_to_string(root.join("src/days/2024/will/day7.rs")).unwrap();
+        assert!(solution.contains("pub struct Day7"));
+        assert!(!solution.contains("DayNUMBER"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn never_overwrites_existing_work() {
+        let root = temp_project("existing");
+        new_day(&root, 2024, &Person::Will, 7).unwrap();
+        fs::write(root.join("src/days/2024/will/day7.rs"), "work in progress").unwrap();
+        fs::write(root.join("data/2024/will/day7/real.txt"), "1 2 3").unwrap();
+
+        let result = new_day(&root, 2024, &Person::Will, 7).unwrap();
+        assert_eq!(result, vec![
+            Scaffolded::AlreadyExists(root.join("src/days/2024/will/day7.rs")),
+            Scaffolded::AlreadyExists(root.join("data/2024/will/day7/real.txt")),
+        ]);
+        assert_eq!(fs::read_to_string(root.join("src/days/2024/will/day7.rs")).unwrap(), "work in progress");
+        assert_eq!(fs::read_to_string(root.join("data/2024/will/day7/real.txt")).unwrap(), "1 2 3");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn rejects_days_outside_advent() {
+        let root = temp_project("outside");
+        assert!(new_day(&root, 2024, &Person::Will, 0).is_err());
+        assert!(new_day(&root, 2024, &Person::Will, 26).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
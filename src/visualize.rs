@@ -0,0 +1,342 @@
+// Frames a solution draws while it runs, so a grid simulation can be watched or saved as images.
+// Drawing costs next to nothing unless the day is being run by `visualize`.
+
+use std::{cell::RefCell, fmt, fs, io::Write, path::PathBuf, thread, time::Duration};
+
+// Where image pixels aren't covered by a symbol
+const BACKGROUND: [u8; 3] = [0x0f, 0x0f, 0x23];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Colour {
+    // For floors and anything else in the background
+    #[default]
+    Dark,
+    Grey,
+    Red,
+    Green,
+    Yellow,
+    Cyan,
+}
+
+impl Colour {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Colour::Dark => [0x33, 0x33, 0x40],
+            Colour::Grey => [0x99, 0x99, 0x99],
+            Colour::Red => [0xff, 0x44, 0x44],
+            Colour::Green => [0x00, 0xcc, 0x00],
+            Colour::Yellow => [0xff, 0xff, 0x66],
+            Colour::Cyan => [0x44, 0xdd, 0xdd],
+        }
+    }
+
+    fn ansi(&self) -> &'static str {
+        match self {
+            Colour::Dark => "\x1b[90m",
+            Colour::Grey => "\x1b[37m",
+            Colour::Red => "\x1b[91m",
+            Colour::Green => "\x1b[92m",
+            Colour::Yellow => "\x1b[93m",
+            Colour::Cyan => "\x1b[96m",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub colour: Colour,
+}
+
+// A grid of coloured symbols and what it shows
+#[derive(Clone, Debug)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    pub caption: String,
+}
+
+impl Frame {
+    // A blank frame, ready to be drawn on
+    pub fn new(width: usize, height: usize) -> Frame {
+        Frame { width, height, cells: vec![Cell { symbol: ' ', colour: Colour::Dark }; width * height], caption: String::new() }
+    }
+
+    // Draw `symbol` at (x, y), ignoring anything off the edge
+    pub fn set(&mut self, x: usize, y: usize, symbol: char, colour: Colour) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = Cell { symbol, colour };
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    // The symbols without their colours, caption first
+    pub fn to_text(&self) -> String {
+        let mut out = format!("{}\n", self.caption);
+        for row in self.rows() {
+            out.extend(row.iter().map(|x| x.symbol));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn to_ansi(&self) -> String {
+        let mut out = format!("\x1b[0m{}\n", self.caption);
+        for row in self.rows() {
+            let mut colour = None;
+            for cell in row {
+                if colour != Some(cell.colour) {
+                    out.push_str(cell.colour.ansi());
+                    colour = Some(cell.colour);
+                }
+                out.push(cell.symbol);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    // RGB pixels with each cell as a `scale` x `scale` square. Returns (width, height, pixels).
+    fn to_pixels(&self, scale: usize) -> (usize, usize, Vec<u8>) {
+        let (width, height) = (self.width * scale, self.height * scale);
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for row in self.rows() {
+            let line = row.iter().flat_map(|cell| {
+                let rgb = if cell.symbol == ' ' { BACKGROUND } else { cell.colour.rgb() };
+                std::iter::repeat_n(rgb, scale).flatten()
+            }).collect::<Vec<u8>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        (width, height, pixels)
+    }
+}
+
+// Some state of a solution that can be drawn, like a warehouse of boxes or a maze with its paths
+pub trait Visualize {
+    fn draw(&self) -> Frame;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum FrameFormat {
+    // Animate the frames in the terminal
+    #[default]
+    Play,
+    // A text file for every frame
+    Text,
+    Ppm,
+    Png,
+}
+
+impl FrameFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            FrameFormat::Play => "",
+            FrameFormat::Text => "txt",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Png => "png",
+        }
+    }
+}
+
+// How to show the frames of a run
+#[derive(Clone, Debug)]
+pub struct Recording {
+    pub format: FrameFormat,
+    // Where the files go, for anything but `Play`
+    pub directory: PathBuf,
+    // How long each frame is shown for when playing
+    pub delay: Duration,
+    // Pixels per cell in images
+    pub scale: usize,
+    // Only keep every this many frames, for days that draw a lot of them
+    pub every: usize,
+}
+
+// What happened to the frames of a run
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Recorded {
+    // Every frame the day drew, including those skipped by `every`
+    pub emitted: usize,
+    pub shown: usize,
+}
+
+struct Recorder {
+    recording: Recording,
+    recorded: Recorded,
+    // Once writing a frame fails, the rest aren't attempted
+    error: Option<String>,
+}
+
+impl Recorder {
+    fn show(&mut self, frame: &Frame) -> std::io::Result<()> {
+        let number = self.recorded.shown + 1;
+        // Nothing is saved for days that don't draw
+        if number == 1 && self.recording.format != FrameFormat::Play {
+            fs::create_dir_all(&self.recording.directory)?;
+        }
+        let filepath = self.recording.directory.join(format!("frame{number:05}.{}", self.recording.format.extension()));
+        match self.recording.format {
+            FrameFormat::Play => {
+                let mut out = std::io::stdout().lock();
+                // Back to the top left of a cleared screen
+                write!(out, "\x1b[H\x1b[2J{}", frame.to_ansi())?;
+                out.flush()?;
+                thread::sleep(self.recording.delay);
+            }
+            FrameFormat::Text => fs::write(filepath, frame.to_text())?,
+            FrameFormat::Ppm => {
+                let (width, height, pixels) = frame.to_pixels(self.recording.scale);
+                let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+                ppm.extend(pixels);
+                fs::write(filepath, ppm)?;
+            }
+            FrameFormat::Png => {
+                let (width, height, pixels) = frame.to_pixels(self.recording.scale);
+                fs::write(filepath, encode_png(width, height, &pixels))?;
+            }
+        }
+        self.recorded.shown = number;
+        Ok(())
+    }
+}
+
+thread_local! {
+    // Set while `record` runs a day on this thread
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+// Whether anything drawn now would be shown, for days that have to keep extra state just to draw it
+pub fn drawing() -> bool {
+    RECORDER.with_borrow(|x| x.is_some())
+}
+
+// Draw `state` as the next frame, if the day is being visualized
+pub fn emit(state: &impl Visualize, caption: impl fmt::Display) {
+    RECORDER.with_borrow_mut(|recorder| {
+        let Some(recorder) = recorder else {
+            return;
+        };
+        recorder.recorded.emitted += 1;
+        if recorder.error.is_some() || (recorder.recorded.emitted - 1) % recorder.recording.every != 0 {
+            return;
+        }
+        let mut frame = state.draw();
+        frame.caption = caption.to_string();
+        if let Err(e) = recorder.show(&frame) {
+            recorder.error = Some(format!("Failed to write frame {}: {e}", recorder.recorded.shown + 1));
+        }
+    });
+}
+
+// Run `f`, showing every frame it emits as `recording` says
+pub fn record<T>(recording: &Recording, f: impl FnOnce() -> T) -> (T, Result<Recorded, String>) {
+    RECORDER.set(Some(Recorder { recording: recording.clone(), recorded: Recorded::default(), error: None }));
+    let result = f();
+    let recorder = RECORDER.take().unwrap();
+    match recorder.error {
+        Some(e) => (result, Err(e)),
+        None => (result, Ok(recorder.recorded)),
+    }
+}
+
+// -----------------------------------------------
+// PNG, written by hand with uncompressed deflate blocks so nothing else is needed
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+    for byte in bytes {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in bytes {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+// An 8-bit RGB image from rows of `pixels`
+fn encode_png(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    // Every row starts with its filter, which is always none
+    let mut raw = Vec::with_capacity(pixels.len() + height);
+    for row in pixels.chunks(width * 3).take(height) {
+        raw.push(0);
+        raw.extend(row);
+    }
+
+    // A zlib stream of stored blocks
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = raw.chunks(u16::MAX as usize).collect::<Vec<&[u8]>>();
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    for (idx, block) in blocks.iter().enumerate() {
+        zlib.push((idx + 1 == blocks.len()) as u8);
+        let len = block.len() as u16;
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend(*block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = vec![];
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bits per channel, RGB, and the only compression, filtering and interlacing there are
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+    png_chunk(&mut out, b"IHDR", &header);
+    png_chunk(&mut out, b"IDAT", &zlib);
+    png_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_the_standard_ones() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn frames_render_as_text_and_pixels() {
+        let mut frame = Frame::new(3, 2);
+        frame.set(0, 0, '#', Colour::Grey);
+        frame.set(2, 1, '@', Colour::Red);
+        frame.set(5, 5, '!', Colour::Red);
+        frame.caption = "Step 1".to_owned();
+        assert_eq!(frame.to_text(), "Step 1\n#  \n  @\n");
+
+        let (width, height, pixels) = frame.to_pixels(2);
+        assert_eq!((width, height, pixels.len()), (6, 4, 6 * 4 * 3));
+        assert_eq!(pixels[0..3], Colour::Grey.rgb());
+        assert_eq!(pixels[6..9], BACKGROUND);
+        assert_eq!(pixels[pixels.len() - 3..], Colour::Red.rgb());
+    }
+}
@@ -0,0 +1,142 @@
+use std::{env, fs, path::{Path, PathBuf}, process, thread, time::{Duration, SystemTime}};
+
+use crate::crash::catch_panic;
+use crate::days::{run_day, Answer, Person, Solution};
+use crate::input::Input;
+use crate::output::{DayResult, OutputFormat, Reporter};
+
+// The watcher restarts itself after a rebuild, so the answers to diff against are handed over through here
+const PREVIOUS_ANSWERS_ENV_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+// When each file was last modified. A file appearing or disappearing is a change too.
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths.iter().map(|path| (path.clone(), fs::metadata(path).and_then(|x| x.modified()).ok())).collect()
+}
+
+// Every file of the person's year, so edits to a `shared.rs` count too
+fn source_files(year: usize, by: &Person) -> Vec<PathBuf> {
+    let dir = format!("src/days/{year}/{}", by.name());
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return vec![];
+    };
+    let mut files = entries
+        .filter_map(|entry| entry.ok().map(|x| x.path()))
+        .filter(|path| path.extension().is_some_and(|x| x == "rs"))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    files
+}
+
+fn previous_answers() -> Option<[Answer; 2]> {
+    let value = env::var(PREVIOUS_ANSWERS_ENV_VAR).ok()?;
+    let (part1, part2) = value.split_once('\t')?;
+    Some([Answer::parse(part1), Answer::parse(part2)])
+}
+
+fn print_diff(previous: &Option<[Answer; 2]>, result: &DayResult) {
+    let Some(previous) = previous else {
+        return;
+    };
+    for (name, before, after) in [("Part 1", &previous[0], &result.part1), ("Part 2", &previous[1], &result.part2)] {
+        if before == after {
+            println!("{name}: unchanged");
+        } else {
+            println!("{name}: {before} -> {after}");
+        }
+    }
+}
+
+// Rebuild, then hand over to the new binary with the same arguments. Returns if the build failed.
+fn rebuild_and_restart(exe: &Path, previous: &Option<[Answer; 2]>) {
+    let mut cargo = process::Command::new(env::var("CARGO").unwrap_or("cargo".to_owned()));
+    cargo.arg("build");
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    match cargo.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("Build failed, waiting for the next change");
+            return;
+        }
+        Err(e) => {
+            println!("Failed to run cargo: {e}");
+            return;
+        }
+    }
+
+    let mut restarted = process::Command::new(exe);
+    restarted.args(env::args().skip(1));
+    if let Some([part1, part2]) = previous {
+        restarted.env(PREVIOUS_ANSWERS_ENV_VAR, format!("{part1}\t{part2}"));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let e = restarted.exec();
+        println!("Failed to restart: {e}");
+        process::exit(1);
+    }
+    #[cfg(not(unix))]
+    match restarted.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            println!("Failed to restart: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub struct WatchOptions {
+    pub part: Option<usize>,
+    // Also rebuild and re-run when the solution changes
+    pub source: bool,
+    pub interval: Duration,
+}
+
+// Run the day, then again every time its input (or source) changes, until interrupted
+pub fn watch(year: usize, by: Person, day_number: usize, mut sol: Box<dyn Solution>, input: Input, options: &WatchOptions) {
+    let input_files = [PathBuf::from(&input.path)];
+    let mut inputs = snapshot(&input_files);
+    let mut sources = if options.source { snapshot(&source_files(year, &by)) } else { vec![] };
+    let mut previous = previous_answers();
+    // Looked up before rebuilding, which replaces the file
+    let exe = env::current_exe().unwrap();
+
+    println!("Watching {}{}. Press Ctrl-C to stop.", input.path, if options.source { " and the solution" } else { "" });
+    loop {
+        let mut result = DayResult::new(year, by.clone(), day_number, &input);
+        if !input.exists() {
+            println!("{} doesn't exist yet", input.path);
+        } else if let Err(crash) = catch_panic(|| run_day(&mut sol, &input, options.part, &mut result)) {
+            // Keep watching, the next change might fix it
+            println!("Day {day_number} crashed: {crash}");
+        } else {
+            Reporter::new(OutputFormat::Text, false).day(&result);
+            print_diff(&previous, &result);
+            previous = Some([result.part1.clone(), result.part2.clone()]);
+        }
+
+        // Wait for something to change
+        loop {
+            thread::sleep(options.interval);
+            if options.source {
+                let now = snapshot(&source_files(year, &by));
+                if now != sources {
+                    sources = now;
+                    println!("\nThe solution changed, rebuilding");
+                    rebuild_and_restart(&exe, &previous);
+                    continue;
+                }
+            }
+            let now = snapshot(&input_files);
+            if now != inputs {
+                inputs = now;
+                println!("\n{} changed", input.path);
+                break;
+            }
+        }
+    }
+}